@@ -0,0 +1,187 @@
+use std::time::{Duration, Instant};
+
+use crate::{Answer, DayError};
+
+/// Summary of the durations measured for one stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+    pub total: Duration,
+}
+
+impl Stats {
+    pub fn new(mut durations: Vec<Duration>) -> Self {
+        durations.sort();
+        let runs = durations.len();
+        if runs == 0 {
+            return Stats {
+                runs,
+                min: Duration::ZERO,
+                median: Duration::ZERO,
+                mean: Duration::ZERO,
+                max: Duration::ZERO,
+                total: Duration::ZERO,
+            };
+        }
+        let total = durations.iter().sum::<Duration>();
+        Stats {
+            runs,
+            min: durations[0],
+            median: durations[runs / 2],
+            mean: total / runs as u32,
+            max: durations[runs - 1],
+            total,
+        }
+    }
+}
+
+/// Timings of one part, parsing and solving being measured separately.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub answer: Answer,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let output = f();
+    (output, start.elapsed())
+}
+
+/// Parses `input` `runs` times, returning the first output with every duration.
+fn parse_runs<'a, T, P>(
+    input: &'a str,
+    runs: usize,
+    parse: P,
+) -> Result<(T, Vec<Duration>), DayError>
+where
+    P: Fn(&'a str) -> Result<T, DayError>,
+{
+    let (parsed, first) = time(|| parse(input));
+    let parsed = parsed?;
+    let mut parse_times = vec![first];
+    for _ in 1..runs.max(1) {
+        parse_times.push(time(|| parse(input)).1);
+    }
+    Ok((parsed, parse_times))
+}
+
+/// Runs `parse` alone `runs` times.
+pub fn measure_parse<'a, T, P>(input: &'a str, runs: usize, parse: P) -> Result<Stats, DayError>
+where
+    P: Fn(&'a str) -> Result<T, DayError>,
+{
+    parse_runs(input, runs, parse).map(|(_, parse_times)| Stats::new(parse_times))
+}
+
+/// Runs `parse` then `solve` `runs` times each. The solver gets a fresh copy of the parsed input
+/// every run, the copy not being part of the measure.
+pub fn measure<'a, T, P, S>(
+    input: &'a str,
+    runs: usize,
+    parse: P,
+    solve: S,
+) -> Result<Sample, DayError>
+where
+    T: Clone,
+    P: Fn(&'a str) -> Result<T, DayError>,
+    S: Fn(T) -> Result<Answer, DayError>,
+{
+    let runs = runs.max(1);
+    let (parsed, parse_times) = parse_runs(input, runs, parse)?;
+
+    let mut solve_times = Vec::with_capacity(runs);
+    let mut answer = None;
+    for _ in 0..runs {
+        let copy = parsed.clone();
+        let (output, elapsed) = time(|| solve(copy));
+        answer = Some(output?);
+        solve_times.push(elapsed);
+    }
+
+    Ok(Sample {
+        answer: answer.expect("at least one run"),
+        parse: Stats::new(parse_times),
+        solve: Stats::new(solve_times),
+    })
+}
+
+/// Criterion benchmarks of a day crate, for its `benches/` target: parsing and solving each
+/// part are measured separately on the shipped `INPUT`, through the day's `bench_parse` and
+/// `bench` functions.
+#[macro_export]
+macro_rules! criterion_benches {
+    ($day:ident) => {
+        fn stages(c: &mut criterion::Criterion) {
+            for part in [1, 2] {
+                let name = format!("{} part {part} parse", stringify!($day));
+                c.bench_function(&name, |b| {
+                    b.iter_custom(|iters| {
+                        $day::bench_parse(part, $day::INPUT, iters as usize)
+                            .expect("the shipped input is parsed")
+                            .total
+                    })
+                });
+                let name = format!("{} part {part} solve", stringify!($day));
+                c.bench_function(&name, |b| {
+                    b.iter_custom(|iters| {
+                        $day::bench(part, $day::INPUT, iters as usize)
+                            .expect("the shipped input is solved")
+                            .solve
+                            .total
+                    })
+                });
+            }
+        }
+
+        criterion::criterion_group! {
+            name = benches;
+            config = criterion::Criterion::default().sample_size(10);
+            targets = stages
+        }
+        criterion::criterion_main!(benches);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(
+            vec![3, 1, 2, 10]
+                .into_iter()
+                .map(Duration::from_millis)
+                .collect(),
+        );
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(4));
+        assert_eq!(stats.max, Duration::from_millis(10));
+        assert_eq!(stats.total, Duration::from_millis(16));
+    }
+
+    #[test]
+    fn test_measure() {
+        let parse = |input: &str| -> Result<Vec<usize>, DayError> {
+            input
+                .split(',')
+                .map(|n| n.parse().map_err(|_| DayError::invalid("not a number")))
+                .collect()
+        };
+        let solve = |numbers: Vec<usize>| Ok(Answer::from(numbers.iter().sum::<usize>()));
+
+        let sample = measure("1,2,3", 5, parse, solve).unwrap();
+        assert_eq!(sample.answer, Answer::Unsigned(6));
+        assert_eq!((sample.parse.runs, sample.solve.runs), (5, 5));
+        assert!(measure("1,x", 5, parse, solve).is_err());
+        assert_eq!(measure_parse("1,2,3", 5, parse).unwrap().runs, 5);
+    }
+}
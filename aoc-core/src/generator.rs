@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/// Small xorshift generator, so that a seed gives the same input on every platform and with every
+/// version of the dependencies.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // splitmix64 step, the state of a xorshift generator must not be zero
+        let mut z = seed.wrapping_add(0x9e3779b97f4a7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        Rng((z ^ (z >> 31)) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Uniform in `0..n`, `n` being positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        range.start() + self.below(range.end() - range.start() + 1)
+    }
+
+    /// True `percent` times out of 100.
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Size parameter of a generator, set with `aoc gen --set name=value`.
+#[derive(Debug)]
+pub struct Param {
+    pub name: &'static str,
+    /// Close to the size of the official input.
+    pub default: usize,
+    pub range: RangeInclusive<usize>,
+    pub help: &'static str,
+}
+
+/// Values of the parameters of one generator.
+#[derive(Debug)]
+pub struct Sizes(HashMap<&'static str, usize>);
+
+impl Sizes {
+    /// Defaults of `params` replaced by the given values, which must be known and in range.
+    pub fn new(params: &'static [Param], values: &[(String, usize)]) -> Result<Self, String> {
+        let mut sizes: HashMap<_, _> = params.iter().map(|p| (p.name, p.default)).collect();
+        for (name, value) in values {
+            let Some(param) = params.iter().find(|p| p.name == name) else {
+                let known: Vec<&str> = params.iter().map(|p| p.name).collect();
+                return Err(format!(
+                    "unknown parameter `{name}`, expected one of: {}",
+                    known.join(", ")
+                ));
+            };
+            if !param.range.contains(value) {
+                return Err(format!(
+                    "`{name}` must be between {} and {}",
+                    param.range.start(),
+                    param.range.end()
+                ));
+            }
+            sizes.insert(param.name, *value);
+        }
+        Ok(Sizes(sizes))
+    }
+
+    /// Value of a parameter declared by the generator.
+    pub fn get(&self, name: &str) -> usize {
+        self.0[name]
+    }
+}
+
+/// Random valid inputs for one day.
+pub struct Generator {
+    pub params: &'static [Param],
+    pub generate: fn(&mut Rng, &Sizes) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, seed: u64, values: &[(String, usize)]) -> Result<String, String> {
+        let sizes = Sizes::new(self.params, values)?;
+        Ok((self.generate)(&mut Rng::new(seed), &sizes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[Param {
+        name: "lines",
+        default: 3,
+        range: 1..=10,
+        help: "number of lines",
+    }];
+
+    #[test]
+    fn test_rng() {
+        let draws = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..20).map(|_| rng.range(5..=9)).collect::<Vec<_>>()
+        };
+        assert_eq!(draws(1), draws(1));
+        assert_ne!(draws(1), draws(2));
+        assert!(draws(0).iter().all(|v| (5..=9).contains(v)));
+
+        let mut items: Vec<usize> = (0..10).collect();
+        Rng::new(3).shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_sizes() {
+        assert_eq!(Sizes::new(PARAMS, &[]).unwrap().get("lines"), 3);
+        let sizes = Sizes::new(PARAMS, &[(String::from("lines"), 7)]).unwrap();
+        assert_eq!(sizes.get("lines"), 7);
+        assert!(Sizes::new(PARAMS, &[(String::from("lines"), 11)]).is_err());
+        let err = Sizes::new(PARAMS, &[(String::from("rows"), 1)]).unwrap_err();
+        assert!(err.contains("lines"));
+    }
+}
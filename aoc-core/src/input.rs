@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Where to read a puzzle input from, as passed to `--input`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `-`
+    Stdin,
+    Path(PathBuf),
+}
+
+impl FromStr for Source {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Source::Stdin,
+            path => Source::Path(PathBuf::from(path)),
+        })
+    }
+}
+
+impl Source {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::Path(path) => fs::read_to_string(path).map_err(|err| in_file(path, err)),
+        }
+    }
+
+    /// Buffered reader over the source, for line by line processing.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::Path(path) => File::open(path)
+                .map(|f| Box::new(BufReader::new(f)) as Box<dyn BufRead>)
+                .map_err(|err| in_file(path, err)),
+        }
+    }
+}
+
+fn in_file(path: &Path, err: io::Error) -> io::Error {
+    io::Error::new(err.kind(), format!("{}: {err}", path.display()))
+}
+
+/// Reads `source`, falling back to the input shipped with the day when none was given.
+pub fn read(source: Option<&Source>, embedded: &'static str) -> io::Result<Cow<'static, str>> {
+    match source {
+        Some(source) => source.read().map(Cow::Owned),
+        None => Ok(Cow::Borrowed(embedded)),
+    }
+}
+
+/// Like [`read`] but without loading the input in memory.
+pub fn open(source: Option<&Source>, embedded: &'static str) -> io::Result<Box<dyn BufRead>> {
+    match source {
+        Some(source) => source.open(),
+        None => Ok(Box::new(embedded.as_bytes())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source() {
+        assert_eq!("-".parse(), Ok(Source::Stdin));
+        assert_eq!("in.txt".parse(), Ok(Source::Path(PathBuf::from("in.txt"))));
+        assert_eq!(read(None, "1 2 3").unwrap(), "1 2 3");
+
+        let missing = Source::Path(PathBuf::from("no/such/input.txt"));
+        let err = read(Some(&missing), "").unwrap_err();
+        assert!(err.to_string().starts_with("no/such/input.txt: "));
+    }
+}
@@ -0,0 +1,199 @@
+use clap::ValueEnum;
+use std::fmt;
+use std::process::ExitCode;
+
+pub mod bench;
+pub mod generator;
+pub mod input;
+pub mod stream;
+
+/// Which half of a puzzle to solve, as passed to `--part`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    #[default]
+    Both,
+}
+
+impl Part {
+    pub fn runs(self, part: u8) -> bool {
+        matches!(
+            (self, part),
+            (Part::One, 1) | (Part::Two, 2) | (Part::Both, 1 | 2)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(usize),
+    Signed(isize),
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Unsigned(v) => write!(f, "{v}"),
+            Answer::Signed(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayError {
+    /// The input does not follow the puzzle format.
+    Parse(aoc_parse::Error),
+    /// The input is well formed but the puzzle cannot be solved on it.
+    Invalid(String),
+}
+
+impl DayError {
+    pub fn at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        DayError::Parse(aoc_parse::Error {
+            line,
+            column,
+            message: message.into(),
+        })
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        DayError::Invalid(message.into())
+    }
+
+    /// Moves a parse error found in a single line, as if on line 1, to line `number` of the input.
+    pub fn on_line(self, number: usize) -> Self {
+        match self {
+            DayError::Parse(mut err) => {
+                err.line += number - 1;
+                DayError::Parse(err)
+            }
+            error => error,
+        }
+    }
+
+    /// Error message followed, for parse errors, by the offending line with a caret under the
+    /// failing column.
+    pub fn report(&self, input: &str) -> String {
+        let DayError::Parse(err) = self else {
+            return self.to_string();
+        };
+        match input.lines().nth(err.line.saturating_sub(1)) {
+            Some(line) => self.report_line(line),
+            None => self.to_string(),
+        }
+    }
+
+    /// Same as [`report`](Self::report) when only the offending line is at hand.
+    pub fn report_line(&self, line: &str) -> String {
+        let DayError::Parse(err) = self else {
+            return self.to_string();
+        };
+        let number = err.line.to_string();
+        let gutter = " ".repeat(number.len());
+        // keep tabs so the caret lines up with the text above it
+        let offset: String = line
+            .chars()
+            .take(err.column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        format!("{self}\n{gutter} |\n{number} | {line}\n{gutter} | {offset}^")
+    }
+}
+
+impl fmt::Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DayError::Parse(err) => write!(f, "parse error at {err}"),
+            DayError::Invalid(message) => write!(f, "invalid input: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for DayError {}
+
+impl From<aoc_parse::Error> for DayError {
+    fn from(err: aoc_parse::Error) -> Self {
+        DayError::Parse(err)
+    }
+}
+
+pub type Solver = fn(&str) -> Result<Answer, DayError>;
+
+/// Prints the answer of each requested part, stopping at the first error. Solvers may be
+/// closures, for days taking options beyond the input.
+pub fn run(
+    part: Part,
+    input: &str,
+    part1: impl Fn(&str) -> Result<Answer, DayError>,
+    part2: impl Fn(&str) -> Result<Answer, DayError>,
+) -> ExitCode {
+    for p in [1, 2] {
+        if !part.runs(p) {
+            continue;
+        }
+        let answer = if p == 1 { part1(input) } else { part2(input) };
+        match answer {
+            Ok(answer) => println!("Part {p}: {answer}"),
+            Err(err) => {
+                eprintln!("{}", err.report(input));
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runs() {
+        assert!(Part::One.runs(1));
+        assert!(!Part::One.runs(2));
+        assert!(Part::Two.runs(2));
+        assert!(Part::Both.runs(1) && Part::Both.runs(2));
+        assert!(!Part::Both.runs(3));
+        assert_eq!(Part::from_str("both", false), Ok(Part::Both));
+        assert_eq!(Part::from_str("1", false), Ok(Part::One));
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from(-3isize).to_string(), "-3");
+    }
+
+    #[test]
+    fn test_report() {
+        let input = "Card 1: 1 2 | 3
+Card 2: 4 x | 5";
+        let err = DayError::at(2, 11, "Unexpected `x`");
+        assert_eq!(
+            err.report(input),
+            "parse error at line 2, column 11: Unexpected `x`
+  |
+2 | Card 2: 4 x | 5
+  |           ^"
+        );
+        let err = DayError::invalid("no start tile");
+        assert_eq!(err.report(input), "invalid input: no start tile");
+        assert_eq!(DayError::at(1, 4, "x").on_line(3), DayError::at(3, 4, "x"));
+        assert_eq!(err.clone().on_line(3), err);
+    }
+}
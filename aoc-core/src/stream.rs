@@ -0,0 +1,166 @@
+use std::fmt;
+use std::io::{self, BufRead};
+use std::ops::AddAssign;
+use std::process::ExitCode;
+
+use crate::{Answer, DayError, Part};
+
+/// Part whose answer is built one input line at a time, so that inputs of any size can be solved
+/// without holding them in memory. Parse errors are positioned relative to the line being fed,
+/// [`solve`] moves them to the line's place in the input.
+pub trait LineSolver {
+    fn feed(&mut self, line: &str) -> Result<(), DayError>;
+    fn finish(&mut self) -> Result<Answer, DayError>;
+}
+
+/// Creates the line solver of a part. [`solve`] and [`run`] also take closures, for solvers
+/// configured by options.
+pub type Streamer = fn(u8) -> Box<dyn LineSolver>;
+
+/// Part whose answer adds up a value computed from each line on its own.
+pub struct LineSum<T> {
+    value: fn(&str) -> Result<T, DayError>,
+    total: T,
+}
+
+impl<T: Default> LineSum<T> {
+    pub fn new(value: fn(&str) -> Result<T, DayError>) -> Self {
+        LineSum {
+            value,
+            total: T::default(),
+        }
+    }
+}
+
+impl<T: AddAssign + Copy + Into<Answer>> LineSolver for LineSum<T> {
+    fn feed(&mut self, line: &str) -> Result<(), DayError> {
+        self.total += (self.value)(line)?;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<Answer, DayError> {
+        Ok(self.total.into())
+    }
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    /// A solver failed, on the given line text when the error comes from one.
+    Day {
+        error: DayError,
+        line: Option<String>,
+    },
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "reading input: {err}"),
+            StreamError::Day {
+                error,
+                line: Some(line),
+            } => write!(f, "{}", error.report_line(line)),
+            StreamError::Day { error, line: None } => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+fn at_line(error: DayError, number: usize, line: &str) -> StreamError {
+    StreamError::Day {
+        error: error.on_line(number),
+        line: Some(line.to_string()),
+    }
+}
+
+/// Feeds every line of `reader` to the solvers of `parts` in a single pass, only one line being
+/// held at a time. Blank lines are skipped, as trailing ones are when parsing a whole input.
+pub fn solve(
+    parts: &[u8],
+    mut reader: impl BufRead,
+    streamer: impl Fn(u8) -> Box<dyn LineSolver>,
+) -> Result<Vec<Answer>, StreamError> {
+    let mut solvers: Vec<_> = parts.iter().map(|&part| streamer(part)).collect();
+    let mut line = String::new();
+    let mut number = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).map_err(StreamError::Io)? == 0 {
+            break;
+        }
+        number += 1;
+        let text = line.trim_end_matches(['\n', '\r']);
+        if text.is_empty() {
+            continue;
+        }
+        for solver in solvers.iter_mut() {
+            solver
+                .feed(text)
+                .map_err(|error| at_line(error, number, text))?;
+        }
+    }
+    solvers
+        .iter_mut()
+        .map(|solver| {
+            solver
+                .finish()
+                .map_err(|error| StreamError::Day { error, line: None })
+        })
+        .collect()
+}
+
+/// Streaming counterpart of [`crate::run`].
+pub fn run(
+    part: Part,
+    reader: impl BufRead,
+    streamer: impl Fn(u8) -> Box<dyn LineSolver>,
+) -> ExitCode {
+    let parts: Vec<u8> = [1, 2].into_iter().filter(|&p| part.runs(p)).collect();
+    match solve(&parts, reader, streamer) {
+        Ok(answers) => {
+            for (p, answer) in parts.iter().zip(answers) {
+                println!("Part {p}: {answer}");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(line: &str) -> Result<usize, DayError> {
+        line.parse()
+            .map_err(|_| DayError::at(1, 1, "Expected a number"))
+    }
+
+    /// Sums the numbers for part 1, counts them for part 2.
+    fn streamer(part: u8) -> Box<dyn LineSolver> {
+        match part {
+            1 => Box::new(LineSum::new(number)),
+            _ => Box::new(LineSum::new(|line| number(line).map(|_| 1usize))),
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let answers = solve(&[1, 2], "1\r\n2\n39".as_bytes(), streamer).unwrap();
+        assert_eq!(answers, vec![Answer::Unsigned(42), Answer::Unsigned(3)]);
+        let answers = solve(&[1, 2], "1\n2\n39\n\n".as_bytes(), streamer).unwrap();
+        assert_eq!(answers, vec![Answer::Unsigned(42), Answer::Unsigned(3)]);
+
+        let Err(StreamError::Day { error, line }) = solve(&[2], "1\n2\nx\n".as_bytes(), streamer)
+        else {
+            panic!("expected a solver error");
+        };
+        assert_eq!(error, DayError::at(3, 1, "Expected a number"));
+        assert_eq!(line.as_deref(), Some("x"));
+    }
+}
@@ -0,0 +1,318 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use aoc_parse::rows;
+use combine::{ParseError, Parser, Stream};
+
+/// `(row, column)` of a cell, the origin being the top left corner.
+pub type Pos = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const DIAGONAL: [(isize, isize); 4] = [(-1, 1), (1, 1), (1, -1), (-1, -1)];
+
+/// Rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `height` rows read from `cells` in row order, `None` if the length does not match.
+    pub fn from_vec(height: usize, width: usize, cells: Vec<T>) -> Option<Self> {
+        (cells.len() == height * width).then_some(Grid {
+            height,
+            width,
+            cells,
+        })
+    }
+
+    /// `None` when the rows do not all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let cells = rows.into_iter().flatten().collect();
+        Some(Grid {
+            height,
+            width,
+            cells,
+        })
+    }
+
+    /// Reads one row per line, `cell` telling which characters are allowed.
+    pub fn from_chars(
+        input: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, aoc_parse::Error> {
+        let mut rows = vec![];
+        for (i, line) in input.lines().enumerate() {
+            let error = |column: usize, message: String| aoc_parse::Error {
+                line: i + 1,
+                column,
+                message,
+            };
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(j, c)| cell(c).ok_or_else(|| error(j + 1, format!("Unexpected `{c}`"))))
+                .collect::<Result<Vec<T>, _>>()?;
+            if let Some(first) = rows.first().map(Vec::len) {
+                if row.len() != first {
+                    return Err(error(
+                        row.len().min(first) + 1,
+                        format!("Expected {first} cells, found {}", row.len()),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows).expect("rows checked while reading"))
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        (row < self.height && col < self.width).then(|| &mut self.cells[row * self.width + col])
+    }
+
+    /// Position one step of `(rows, columns)` away from `pos`, if it is still in the grid.
+    pub fn offset(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let row = row.checked_add_signed(dr).filter(|&r| r < self.height)?;
+        let col = col.checked_add_signed(dc).filter(|&c| c < self.width)?;
+        Some((row, col))
+    }
+
+    /// Up, right, down and left neighbours that are inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    /// Orthogonal then diagonal neighbours that are inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .chain(DIAGONAL.iter())
+            .filter_map(move |&d| self.offset(pos, d))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells[col..]
+            .iter()
+            .step_by(self.width.max(1))
+            .take(self.height)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i / self.width, i % self.width), cell))
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            height: self.height,
+            width: self.width,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Builds a grid of the given size whose cell at `(row, col)` is `cell((row, col))`.
+    fn from_fn(height: usize, width: usize, cell: impl Fn(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(cell)
+            .collect();
+        Grid {
+            height,
+            width,
+            cells,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(height: usize, width: usize, value: T) -> Self {
+        Grid {
+            height,
+            width,
+            cells: vec![value; height * width],
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+
+    /// Quarter turn clockwise: the first column, read bottom-up, becomes the first row.
+    pub fn rotate_clockwise(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(self.height - 1 - col, row)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(col, self.width - 1 - row)].clone()
+        })
+    }
+
+    /// Mirrors left and right.
+    pub fn flip_horizontal(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(row, self.width - 1 - col)].clone()
+        })
+    }
+
+    /// Mirrors top and bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(self.height - 1 - row, col)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Pos) -> &T {
+        assert!(
+            row < self.height && col < self.width,
+            "({row}, {col}) is outside the grid"
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Pos) -> &mut T {
+        assert!(
+            row < self.height && col < self.width,
+            "({row}, {col}) is outside the grid"
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+/// One line per row, each cell being written with its own `Display`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Grid parser for use inside larger inputs, with one `cell` per character.
+pub fn grid<Input, P>(cell: P) -> impl Parser<Input, Output = Grid<P::Output>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    P: Parser<Input>,
+{
+    rows(cell).map(|rows| Grid::from_rows(rows).expect("rows have the same length"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use combine::parser::char::{char, newline};
+    use combine::{choice, sep_end_by1};
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::from_chars(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_from_chars() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+
+        let err = Grid::from_chars("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = Grid::from_chars("12\n345", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789");
+        let corner: Vec<Pos> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours4((2, 2)).count(), 2);
+        assert_eq!(grid.offset((1, 1), (-2, 0)), None);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "36\n25\n14");
+        assert_eq!(grid.flip_horizontal().to_string(), "321\n654");
+        assert_eq!(grid.flip_vertical().to_string(), "456\n123");
+        let turned = (0..4).fold(grid.clone(), |g, _| g.rotate_clockwise());
+        assert_eq!(turned, grid);
+    }
+
+    #[test]
+    fn test_grid_parser() {
+        let cell = || choice((char('.').map(|_| false), char('#').map(|_| true)));
+        let input = "#.\n.#\n\n##\n";
+        let output = aoc_parse::parse(sep_end_by1(grid(cell()), newline()), input);
+        let grids: Vec<Grid<bool>> = output.unwrap();
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[0].position(|&c| !c), Some((0, 1)));
+        assert_eq!(
+            grids[1].map(|&c| if c { '#' } else { '.' }).to_string(),
+            "##"
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use std::fmt;
+use std::str::FromStr;
+
+use combine::{
+    eof,
+    error::StreamError,
+    many1, optional,
+    parser::char::{char, digit, newline, spaces, string},
+    satisfy, sep_end_by1, skip_many,
+    stream::{
+        easy,
+        position::{self, SourcePosition},
+        StreamErrorFor,
+    },
+    EasyParser, ParseError, Parser, Stream,
+};
+
+/// First error found while parsing a puzzle input, with its 1-based position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl<T, R> From<easy::Errors<T, R, SourcePosition>> for Error
+where
+    T: fmt::Display,
+    R: fmt::Display,
+{
+    fn from(errors: easy::Errors<T, R, SourcePosition>) -> Self {
+        // The first line of the combine message only repeats the position
+        let message = errors
+            .to_string()
+            .lines()
+            .skip(1)
+            .filter(|l| !l.is_empty())
+            .collect::<Vec<&str>>()
+            .join(", ");
+        Error {
+            line: errors.position.line as usize,
+            column: errors.position.column as usize,
+            message,
+        }
+    }
+}
+
+pub type PositionStream<'a> = easy::Stream<position::Stream<&'a str, SourcePosition>>;
+
+/// Parses the whole input, only allowing trailing whitespace after `parser`.
+pub fn parse<'a, P>(parser: P, input: &'a str) -> Result<P::Output, Error>
+where
+    P: Parser<PositionStream<'a>>,
+{
+    parser
+        .skip(spaces())
+        .skip(eof())
+        .easy_parse(position::Stream::new(input))
+        .map(|(output, _)| output)
+        .map_err(Error::from)
+}
+
+fn number<Input, T>(digits: String) -> Result<T, StreamErrorFor<Input>>
+where
+    Input: Stream<Token = char>,
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    digits.parse::<T>().map_err(StreamErrorFor::<Input>::other)
+}
+
+/// Unsigned integer, failing instead of panicking when it overflows `T`.
+pub fn unsigned<Input, T>() -> impl Parser<Input, Output = T>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    many1(digit()).and_then(number::<Input, T>)
+}
+
+/// Integer with an optional leading `-`.
+pub fn signed<Input, T>() -> impl Parser<Input, Output = T>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    (optional(char('-')), many1(digit()))
+        .map(|(sign, digits): (Option<char>, String)| match sign {
+            Some(_) => format!("-{digits}"),
+            None => digits,
+        })
+        .and_then(number::<Input, T>)
+}
+
+/// Spaces and tabs, but not newlines.
+pub fn blanks<Input>() -> impl Parser<Input, Output = ()>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    skip_many(satisfy(|c| c == ' ' || c == '\t'))
+}
+
+/// At least one item, separated by blanks on a single line, e.g. `41 48 83  6`.
+pub fn list<Input, P>(item: P) -> impl Parser<Input, Output = Vec<P::Output>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    P: Parser<Input>,
+{
+    blanks().with(many1(item.skip(blanks())))
+}
+
+/// Labelled line such as `seeds: 79 14 55 13`, returning what `values` parsed.
+pub fn header<Input, P>(label: &'static str, values: P) -> impl Parser<Input, Output = P::Output>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    P: Parser<Input>,
+{
+    (string(label), char(':'), blanks()).with(values)
+}
+
+/// One `item` per line, with an optional trailing newline.
+pub fn lines<Input, P>(item: P) -> impl Parser<Input, Output = Vec<P::Output>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    P: Parser<Input>,
+{
+    sep_end_by1(item, newline())
+}
+
+/// One row of `cell`s per line, failing unless all rows have the same length.
+pub fn rows<Input, P>(cell: P) -> impl Parser<Input, Output = Vec<Vec<P::Output>>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    P: Parser<Input>,
+{
+    lines(many1::<Vec<P::Output>, _, _>(cell)).and_then(|rows: Vec<Vec<P::Output>>| {
+        let width = rows[0].len();
+        if rows.iter().all(|row| row.len() == width) {
+            Ok(rows)
+        } else {
+            Err(StreamErrorFor::<Input>::message_static_message(
+                "rows have different lengths",
+            ))
+        }
+    })
+}
+
+/// Groups of lines separated by a blank line.
+pub fn blocks<Input, P>(line: P) -> impl Parser<Input, Output = Vec<Vec<P::Output>>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    P: Parser<Input>,
+{
+    sep_end_by1(lines(line), newline())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use combine::parser::char::letter;
+
+    #[test]
+    fn test_unsigned() {
+        assert_eq!(parse(unsigned::<_, usize>(), "4323"), Ok(4323));
+        let err = parse(unsigned::<_, u8>(), "256").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert!(err.message.contains("too large"), "{}", err.message);
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(
+            parse(list(signed::<_, isize>()), "-7 13 -20"),
+            Ok(vec![-7, 13, -20])
+        );
+        assert!(parse(signed::<_, isize>(), "-").is_err());
+    }
+
+    #[test]
+    fn test_list() {
+        let output = parse(list(unsigned::<_, usize>()), " 1 21  53 59 ");
+        assert_eq!(output, Ok(vec![1, 21, 53, 59]));
+        assert!(parse(list(unsigned::<_, usize>()), "").is_err());
+    }
+
+    #[test]
+    fn test_header() {
+        let output = parse(
+            header("seeds", list(unsigned::<_, usize>())),
+            "seeds: 79 14 55 13",
+        );
+        assert_eq!(output, Ok(vec![79, 14, 55, 13]));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "ab
+cd
+
+ef
+";
+        let output = parse(blocks(many1::<String, _, _>(letter())), input);
+        assert_eq!(
+            output,
+            Ok(vec![
+                vec![String::from("ab"), String::from("cd")],
+                vec![String::from("ef")]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_rows() {
+        let output = parse(rows(letter()), "ab\ncd\n");
+        assert_eq!(output, Ok(vec![vec!['a', 'b'], vec!['c', 'd']]));
+        let err = parse(rows(letter()), "ab\nc").unwrap_err();
+        assert!(err.message.contains("different lengths"), "{}", err.message);
+    }
+
+    #[test]
+    fn test_error_position() {
+        let input = "1 2
+3 x";
+        let err = parse(lines(list(unsigned::<_, usize>())), input).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(
+            err.to_string(),
+            format!("line 2, column 3: {}", err.message)
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_core::bench::{Sample, Stats};
+use serde::Serialize;
+
+/// Durations of one stage, in nanoseconds.
+#[derive(Debug, Serialize)]
+pub struct StageReport {
+    pub runs: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: u128,
+    pub max_ns: u128,
+}
+
+impl From<Stats> for StageReport {
+    fn from(stats: Stats) -> Self {
+        StageReport {
+            runs: stats.runs,
+            min_ns: stats.min.as_nanos(),
+            median_ns: stats.median.as_nanos(),
+            mean_ns: stats.mean.as_nanos(),
+            max_ns: stats.max.as_nanos(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartReport {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse: StageReport,
+    pub solve: StageReport,
+}
+
+impl PartReport {
+    pub fn new(day: u8, part: u8, sample: Sample) -> Self {
+        PartReport {
+            day,
+            part,
+            answer: sample.answer.to_string(),
+            parse: sample.parse.into(),
+            solve: sample.solve.into(),
+        }
+    }
+}
+
+/// Whole `aoc bench` run, written as JSON so that two commits can be diffed.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub runs: usize,
+    pub parts: Vec<PartReport>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_report() {
+        let day = registry::find(6).unwrap();
+        let sample = (day.bench)(1, day.input, 3).unwrap();
+        let report = Report {
+            runs: 3,
+            parts: vec![PartReport::new(6, 1, sample)],
+        };
+        let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["parts"][0]["answer"], "140220");
+        assert_eq!(json["parts"][0]["parse"]["runs"], 3);
+        assert!(json["parts"][0]["solve"]["median_ns"].is_u64());
+    }
+}
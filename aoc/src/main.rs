@@ -0,0 +1,232 @@
+use anyhow::{anyhow, Result};
+use aoc_core::input::{self, Source};
+use aoc_core::Part;
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+mod bench;
+mod registry;
+mod verify;
+
+#[derive(Debug, Parser)]
+#[clap(name = "aoc", about = "Runs the Advent of Code solvers")]
+struct Opts {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solver of one day on an input file
+    Run {
+        #[clap(short, long)]
+        day: u8,
+        #[clap(short, long, value_enum, default_value = "both")]
+        part: Part,
+        /// Input file, `-` for stdin; the day's shipped input when omitted
+        #[clap(short, long)]
+        input: Option<Source>,
+        /// Read the input line by line instead of loading it whole
+        #[clap(long)]
+        stream: bool,
+    },
+    /// List the registered days
+    List,
+    /// Check every day against the known answers for its shipped input
+    Verify {
+        #[clap(short, long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Only check this day
+        #[clap(short, long)]
+        day: Option<u8>,
+        /// Store the answers that are missing from the file
+        #[clap(long)]
+        record: bool,
+    },
+    /// Time parsing and solving of every day on its shipped input
+    Bench {
+        /// Only time this day
+        #[clap(short, long)]
+        day: Option<u8>,
+        #[clap(short, long, value_enum, default_value = "both")]
+        part: Part,
+        /// Number of times each stage is repeated
+        #[clap(short, long, default_value_t = 10)]
+        runs: usize,
+        /// Write the timings to this file as JSON
+        #[clap(long)]
+        json: Option<PathBuf>,
+    },
+    /// Write a random input for one day, to see how the solvers scale
+    Gen {
+        #[clap(short, long)]
+        day: u8,
+        /// The same seed always gives the same input
+        #[clap(long, default_value_t = 0)]
+        seed: u64,
+        /// Size parameter as `name=value`, `aoc list` shows those of each day
+        #[clap(short, long = "set", value_parser = parse_size)]
+        sizes: Vec<(String, usize)>,
+        /// Write the input to this file instead of standard output
+        #[clap(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+fn parse_size(s: &str) -> Result<(String, usize)> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| anyhow!("expected `name=value`"))?;
+    Ok((name.to_string(), value.parse()?))
+}
+
+fn main() -> Result<ExitCode> {
+    match Opts::parse().command {
+        Command::Run {
+            day,
+            part,
+            input,
+            stream,
+        } => {
+            let solution = registry::find(day).ok_or_else(|| anyhow!("No solver for day {day}"))?;
+            if stream {
+                let streamer = solution
+                    .stream
+                    .ok_or_else(|| anyhow!("Day {day} cannot be streamed"))?;
+                let reader = input::open(input.as_ref(), solution.input)?;
+                return Ok(aoc_core::stream::run(part, reader, streamer));
+            }
+            let input = input::read(input.as_ref(), solution.input)?;
+            return Ok(aoc_core::run(part, &input, solution.part1, solution.part2));
+        }
+        Command::List => {
+            for d in registry::DAYS.iter() {
+                println!("day {:02}", d.day);
+                for p in d.generator.params {
+                    let size = format!("{}={}", p.name, p.default);
+                    let (start, end) = (p.range.start(), p.range.end());
+                    println!("  {size:<18} {}, {start} to {end}", p.help);
+                }
+            }
+        }
+        Command::Verify {
+            answers,
+            day,
+            record,
+        } => return verify(&answers, day, record),
+        Command::Bench {
+            day,
+            part,
+            runs,
+            json,
+        } => return bench(day, part, runs, json.as_deref()),
+        Command::Gen {
+            day,
+            seed,
+            sizes,
+            output,
+        } => {
+            let solution = registry::find(day).ok_or_else(|| anyhow!("No solver for day {day}"))?;
+            let input = (solution.generator)
+                .generate(seed, &sizes)
+                .map_err(|err| anyhow!("day {day}: {err}"))?;
+            match output {
+                Some(path) => fs::write(&path, input)?,
+                None => io::stdout().write_all(input.as_bytes())?,
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn verify(path: &Path, day: Option<u8>, record: bool) -> Result<ExitCode> {
+    let mut answers = verify::Answers::load(path)?;
+    let mut regressions = 0;
+    let mut recorded = 0;
+
+    println!(
+        "{:>3}  {:>4}  {:<7}  {:>16}  {:>16}",
+        "day", "part", "status", "expected", "actual"
+    );
+    for d in registry::DAYS
+        .iter()
+        .filter(|d| day.is_none_or(|n| n == d.day))
+    {
+        for part in [1, 2] {
+            let check = verify::check(d, part, &answers);
+            println!(
+                "{:>3}  {:>4}  {:<7}  {:>16}  {:>16}",
+                check.day,
+                check.part,
+                check.status,
+                check.expected.as_deref().unwrap_or("-"),
+                check.actual
+            );
+            match check.status {
+                verify::Status::Fail => regressions += 1,
+                verify::Status::Missing if record => {
+                    answers.insert(verify::Entry {
+                        day: d.day,
+                        part,
+                        input: verify::input_hash(d.input),
+                        answer: check.actual,
+                    });
+                    recorded += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+    if recorded > 0 {
+        answers.save(path)?;
+        println!("Recorded {recorded} answers in {}", path.display());
+    }
+    if regressions > 0 {
+        eprintln!("{regressions} answers do not match {}", path.display());
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn bench(day: Option<u8>, part: Part, runs: usize, json: Option<&Path>) -> Result<ExitCode> {
+    let mut report = bench::Report {
+        runs,
+        parts: vec![],
+    };
+
+    println!(
+        "{:>3}  {:>4}  {:>16}  {:>12}  {:>12}",
+        "day", "part", "answer", "parse", "solve"
+    );
+    for d in registry::DAYS
+        .iter()
+        .filter(|d| day.is_none_or(|n| n == d.day))
+    {
+        for p in [1, 2].into_iter().filter(|p| part.runs(*p)) {
+            let sample = match (d.bench)(p, d.input, runs) {
+                Ok(sample) => sample,
+                Err(err) => {
+                    eprintln!("day {:02} part {p}: {}", d.day, err.report(d.input));
+                    return Ok(ExitCode::FAILURE);
+                }
+            };
+            println!(
+                "{:>3}  {:>4}  {:>16}  {:>12}  {:>12}",
+                d.day,
+                p,
+                sample.answer.to_string(),
+                format!("{:.2?}", sample.parse.median),
+                format!("{:.2?}", sample.solve.median)
+            );
+            report.parts.push(bench::PartReport::new(d.day, p, sample));
+        }
+    }
+    if let Some(path) = json {
+        fs::write(path, serde_json::to_string_pretty(&report)?)?;
+        println!("Wrote timings to {}", path.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
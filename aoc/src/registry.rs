@@ -0,0 +1,253 @@
+use aoc_core::bench::Sample;
+use aoc_core::generator::Generator;
+use aoc_core::stream::Streamer;
+use aoc_core::{DayError, Solver};
+
+/// Times one part of a day on an input, with the number of runs.
+pub type Bench = fn(u8, &str, usize) -> Result<Sample, DayError>;
+
+/// Both solvers of one day, taking the raw puzzle input, and the input shipped with it.
+pub struct Day {
+    pub day: u8,
+    pub part1: Solver,
+    pub part2: Solver,
+    pub input: &'static str,
+    pub bench: Bench,
+    /// Line by line solvers, for the days whose input is a list of independent lines.
+    pub stream: Option<Streamer>,
+    pub generator: Generator,
+}
+
+impl Day {
+    pub fn solver(&self, part: u8) -> Solver {
+        match part {
+            1 => self.part1,
+            _ => self.part2,
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        part1: day01::part1,
+        part2: day01::part2,
+        input: day01::INPUT,
+        bench: day01::bench,
+        stream: Some(day01::stream),
+        generator: day01::generator::GENERATOR,
+    },
+    Day {
+        day: 2,
+        part1: day02::part1,
+        part2: day02::part2,
+        input: day02::INPUT,
+        bench: day02::bench,
+        stream: Some(day02::stream),
+        generator: day02::generator::GENERATOR,
+    },
+    Day {
+        day: 3,
+        part1: day03::part1,
+        part2: day03::part2,
+        input: day03::INPUT,
+        bench: day03::bench,
+        stream: Some(day03::stream),
+        generator: day03::generator::GENERATOR,
+    },
+    Day {
+        day: 4,
+        part1: day04::part1,
+        part2: day04::part2,
+        input: day04::INPUT,
+        bench: day04::bench,
+        stream: Some(day04::stream),
+        generator: day04::generator::GENERATOR,
+    },
+    Day {
+        day: 5,
+        part1: day05::part1,
+        part2: day05::part2,
+        input: day05::INPUT,
+        bench: day05::bench,
+        stream: None,
+        generator: day05::generator::GENERATOR,
+    },
+    Day {
+        day: 6,
+        part1: day06::part1,
+        part2: day06::part2,
+        input: day06::INPUT,
+        bench: day06::bench,
+        stream: None,
+        generator: day06::generator::GENERATOR,
+    },
+    Day {
+        day: 7,
+        part1: day07::part1,
+        part2: day07::part2,
+        input: day07::INPUT,
+        bench: day07::bench,
+        stream: Some(day07::stream),
+        generator: day07::generator::GENERATOR,
+    },
+    Day {
+        day: 8,
+        part1: day08::part1,
+        part2: day08::part2,
+        input: day08::INPUT,
+        bench: day08::bench,
+        stream: None,
+        generator: day08::generator::GENERATOR,
+    },
+    Day {
+        day: 9,
+        part1: day09::part1,
+        part2: day09::part2,
+        input: day09::INPUT,
+        bench: day09::bench,
+        stream: Some(day09::stream),
+        generator: day09::generator::GENERATOR,
+    },
+    Day {
+        day: 10,
+        part1: day10::part1,
+        part2: day10::part2,
+        input: day10::INPUT,
+        bench: day10::bench,
+        stream: None,
+        generator: day10::generator::GENERATOR,
+    },
+    Day {
+        day: 11,
+        part1: day11::part1,
+        part2: day11::part2,
+        input: day11::INPUT,
+        bench: day11::bench,
+        stream: None,
+        generator: day11::generator::GENERATOR,
+    },
+    Day {
+        day: 12,
+        part1: day12::part1,
+        part2: day12::part2,
+        input: day12::INPUT,
+        bench: day12::bench,
+        stream: Some(day12::stream),
+        generator: day12::generator::GENERATOR,
+    },
+    Day {
+        day: 13,
+        part1: day13::part1,
+        part2: day13::part2,
+        input: day13::INPUT,
+        bench: day13::bench,
+        stream: None,
+        generator: day13::generator::GENERATOR,
+    },
+    Day {
+        day: 14,
+        part1: day14::part1,
+        part2: day14::part2,
+        input: day14::INPUT,
+        bench: day14::bench,
+        stream: None,
+        generator: day14::generator::GENERATOR,
+    },
+    Day {
+        day: 15,
+        part1: day15::part1,
+        part2: day15::part2,
+        input: day15::INPUT,
+        bench: day15::bench,
+        stream: None,
+        generator: day15::generator::GENERATOR,
+    },
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::stream::{self, StreamError};
+    use aoc_core::Answer;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_unique_entries() {
+        let keys: HashSet<u8> = DAYS.iter().map(|d| d.day).collect();
+        assert_eq!(keys.len(), DAYS.len());
+    }
+
+    #[test]
+    fn test_find() {
+        let day = find(6).unwrap();
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+        assert_eq!((day.part1)(input), Ok(Answer::Unsigned(288)));
+        assert_eq!((day.part2)(input), Ok(Answer::Unsigned(71503)));
+        assert!(find(26).is_none());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let day = find(9).unwrap();
+        let Err(DayError::Parse(err)) = (day.part1)("0 3 6\n1 x 5") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    fn test_stream_error() {
+        let streamer = find(9).and_then(|day| day.stream).unwrap();
+        let input = "0 3 6\n1 x 5".as_bytes();
+        let Err(StreamError::Day { error, line }) = stream::solve(&[1], input, streamer) else {
+            panic!("expected a parse error");
+        };
+        let DayError::Parse(err) = error else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(line.as_deref(), Some("1 x 5"));
+        assert!(find(6).unwrap().stream.is_none());
+    }
+
+    #[test]
+    fn test_generators() {
+        for day in DAYS {
+            let generate = |seed, sizes: &[(String, usize)]| day.generator.generate(seed, sizes);
+            for seed in 0..3 {
+                let input = generate(seed, &[]).unwrap();
+                assert_eq!(generate(seed, &[]), Ok(input.clone()), "day {}", day.day);
+                assert!((day.part1)(&input).is_ok(), "day {} seed {seed}", day.day);
+                assert!((day.part2)(&input).is_ok(), "day {} seed {seed}", day.day);
+            }
+            assert_ne!(generate(0, &[]), generate(1, &[]), "day {}", day.day);
+
+            for param in day.generator.params {
+                let size = |value| [(String::from(param.name), value)];
+                let (start, end) = (*param.range.start(), *param.range.end());
+                let other = if param.default == start {
+                    start + 1
+                } else {
+                    start
+                };
+                assert_ne!(
+                    generate(0, &size(other)),
+                    generate(0, &[]),
+                    "day {} {}",
+                    day.day,
+                    param.name
+                );
+                assert!(generate(0, &size(end + 1)).is_err());
+                if start > 0 {
+                    assert!(generate(0, &size(start - 1)).is_err());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::registry::Day;
+
+/// Known-good answer of one part for one input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Contents of the answers file, one `[[answer]]` table per entry.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    pub entries: Vec<Entry>,
+}
+
+impl Answers {
+    /// Reads the answers file, a missing file being an empty database.
+    pub fn load(path: &Path) -> Result<Answers> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let content =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, toml::to_string_pretty(self)?)
+            .with_context(|| format!("writing {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|e| e.day == day && e.part == part && e.input == input)
+            .map(|e| e.answer.as_str())
+    }
+
+    /// Adds an entry, keeping the file sorted by day and part.
+    pub fn insert(&mut self, entry: Entry) {
+        self.entries
+            .retain(|e| (e.day, e.part, &e.input) != (entry.day, entry.part, &entry.input));
+        self.entries.push(entry);
+        self.entries.sort_by_key(|e| (e.day, e.part));
+    }
+}
+
+/// FNV-1a hash of the input, stable across platforms and compiler versions.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+        };
+        // pad so the table columns line up
+        f.pad(s)
+    }
+}
+
+/// Result of running one part against the answers file.
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub expected: Option<String>,
+    /// The answer, or the error message when the solver failed.
+    pub actual: String,
+}
+
+pub fn check(day: &Day, part: u8, answers: &Answers) -> Check {
+    let expected = answers
+        .get(day.day, part, &input_hash(day.input))
+        .map(String::from);
+    let actual = (day.solver(part))(day.input).map(|answer| answer.to_string());
+    let status = match (&expected, &actual) {
+        (_, Err(_)) => Status::Fail,
+        (None, Ok(_)) => Status::Missing,
+        (Some(expected), Ok(actual)) if expected == actual => Status::Pass,
+        (Some(_), Ok(_)) => Status::Fail,
+    };
+    Check {
+        day: day.day,
+        part,
+        status,
+        expected,
+        actual: actual.unwrap_or_else(|err| err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_answers() {
+        let mut answers: Answers = toml::from_str(
+            r#"
+[[answer]]
+day = 6
+part = 1
+input = "0123"
+answer = "288"
+"#,
+        )
+        .unwrap();
+        assert_eq!(answers.get(6, 1, "0123"), Some("288"));
+        assert_eq!(answers.get(6, 2, "0123"), None);
+
+        answers.insert(Entry {
+            day: 6,
+            part: 1,
+            input: String::from("0123"),
+            answer: String::from("71503"),
+        });
+        assert_eq!(answers.entries.len(), 1);
+        assert_eq!(answers.get(6, 1, "0123"), Some("71503"));
+    }
+
+    #[test]
+    fn test_check() {
+        let day = registry::find(6).unwrap();
+        let mut answers = Answers::default();
+        assert_eq!(check(day, 1, &answers).status, Status::Missing);
+
+        let entry = |answer: &str| Entry {
+            day: 6,
+            part: 1,
+            input: input_hash(day.input),
+            answer: String::from(answer),
+        };
+        answers.insert(entry("140220"));
+        assert_eq!(check(day, 1, &answers).status, Status::Pass);
+        answers.insert(entry("0"));
+        assert_eq!(check(day, 1, &answers).status, Status::Fail);
+    }
+}
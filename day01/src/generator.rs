@@ -0,0 +1,53 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "lines",
+            default: 1000,
+            range: 1..=100_000_000,
+            help: "number of lines",
+        },
+        Param {
+            name: "length",
+            default: 50,
+            range: 1..=10_000,
+            help: "maximum length of a line",
+        },
+    ],
+    generate,
+};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn digit(rng: &mut Rng) -> char {
+    char::from(b'0' + rng.range(1..=9) as u8)
+}
+
+/// Letters, digits and spelled-out digits, with at least one digit for part 1.
+fn line(rng: &mut Rng, length: usize) -> String {
+    let target = rng.range(1..=length);
+    let mut line = String::new();
+    while line.len() < target {
+        match rng.below(4) {
+            0 => line.push(digit(rng)),
+            1 => line.push_str(WORDS[rng.below(WORDS.len())]),
+            _ => line.push(char::from(b'a' + rng.below(26) as u8)),
+        }
+    }
+    if !line.bytes().any(|b| b.is_ascii_digit()) {
+        let at = rng.below(line.len() + 1);
+        line.insert(at, digit(rng));
+    }
+    line
+}
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let length = sizes.get("length");
+    (0..sizes.get("lines"))
+        .map(|_| line(rng, length))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
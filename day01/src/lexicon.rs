@@ -0,0 +1,208 @@
+use aho_corasick::AhoCorasick;
+use anyhow::{anyhow, bail, Context, Result};
+use std::sync::OnceLock;
+
+/// Languages whose digit words are built in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Language {
+    English,
+    French,
+    German,
+    Spanish,
+}
+
+impl Language {
+    /// Words of the digits 1 to 9.
+    fn words(self) -> [&'static str; 9] {
+        match self {
+            Language::English => [
+                "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+            ],
+            Language::French => [
+                "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+            ],
+            Language::German => [
+                "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+            ],
+            Language::Spanish => [
+                "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+            ],
+        }
+    }
+}
+
+/// Digit read in a line, `start..end` being the byte range of its digit or word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub start: usize,
+    pub end: usize,
+    pub digit: char,
+    /// Read from a word rather than a digit.
+    pub spelled: bool,
+}
+
+/// Spelled-out digits recognised in a calibration line, of any length and in any script.
+#[derive(Debug, Clone, Default)]
+pub struct DigitLexicon {
+    words: Vec<(String, char)>,
+    /// Built on the first scan, dropped whenever a word is added.
+    scanner: OnceLock<Scanner>,
+}
+
+/// Automaton matching the digits and every word at once; pattern `i` reads `digits[i]`.
+#[derive(Debug, Clone)]
+struct Scanner {
+    automaton: AhoCorasick,
+    digits: Vec<char>,
+}
+
+impl DigitLexicon {
+    /// Lexicon reading digits only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn builtin(language: Language) -> Self {
+        let mut lexicon = Self::new();
+        lexicon.add_language(language);
+        lexicon
+    }
+
+    pub fn add_language(&mut self, language: Language) {
+        for (word, digit) in language.words().into_iter().zip('1'..='9') {
+            self.add_word(word, digit);
+        }
+    }
+
+    /// Adds or redefines a word.
+    pub fn add_word(&mut self, word: &str, digit: char) {
+        if word.is_empty() {
+            return;
+        }
+        match self.words.iter_mut().find(|(w, _)| w == word) {
+            Some(entry) => entry.1 = digit,
+            None => self.words.push((word.to_string(), digit)),
+        }
+        self.scanner.take();
+    }
+
+    /// Adds the words of a `word=digit` list, one per line; blank lines and lines starting with
+    /// `#` are skipped.
+    pub fn add_words(&mut self, text: &str) -> Result<()> {
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let entry = || format!("line {}: {line}", i + 1);
+            let (word, digit) = line
+                .split_once('=')
+                .ok_or_else(|| anyhow!("expected `word=digit`"))
+                .with_context(entry)?;
+            let (word, digit) = (word.trim(), digit.trim());
+            let mut chars = digit.chars();
+            let digit = match (chars.next(), chars.next()) {
+                (Some(d), None) if d.is_ascii_digit() => d,
+                _ => return Err(anyhow!("`{digit}` is not a digit")).with_context(entry),
+            };
+            if word.is_empty() || word.starts_with(|c: char| c.is_ascii_digit()) {
+                bail!("{}: a word cannot be empty or start with a digit", entry());
+            }
+            self.add_word(word, digit);
+        }
+        Ok(())
+    }
+
+    fn scanner(&self) -> &Scanner {
+        self.scanner.get_or_init(|| {
+            let digits = ('0'..='9').map(|d| (d.to_string(), d));
+            let (patterns, digits): (Vec<String>, Vec<char>) =
+                digits.chain(self.words.iter().cloned()).unzip();
+            Scanner {
+                automaton: AhoCorasick::new(patterns).expect("digit words fit in an automaton"),
+                digits,
+            }
+        })
+    }
+
+    /// Digits and words of `line` in a single pass, overlapping ones included, ordered by start
+    /// then shortest first.
+    pub fn tokens(&self, line: &str) -> Vec<Token> {
+        let scanner = self.scanner();
+        let mut tokens: Vec<Token> = scanner
+            .automaton
+            .find_overlapping_iter(line)
+            .map(|m| Token {
+                start: m.start(),
+                end: m.end(),
+                digit: scanner.digits[m.pattern().as_usize()],
+                // the ten digits come first
+                spelled: m.pattern().as_usize() >= 10,
+            })
+            .collect();
+        tokens.sort_by_key(|token| (token.start, token.end));
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(lexicon: &DigitLexicon, line: &str) -> String {
+        lexicon
+            .tokens(line)
+            .iter()
+            .map(|token| token.digit)
+            .collect()
+    }
+
+    #[test]
+    fn test_tokens() {
+        let english = DigitLexicon::builtin(Language::English);
+        assert_eq!(digits(&english, "xtwone3eightwo"), "21382");
+        assert_eq!(
+            english.tokens("twone"),
+            vec![
+                Token {
+                    start: 0,
+                    end: 3,
+                    digit: '2',
+                    spelled: true,
+                },
+                Token {
+                    start: 2,
+                    end: 5,
+                    digit: '1',
+                    spelled: true,
+                },
+            ]
+        );
+        assert_eq!(digits(&DigitLexicon::new(), "one2three0"), "20");
+
+        let mut lexicon = DigitLexicon::builtin(Language::German);
+        lexicon.add_language(Language::French);
+        assert_eq!(digits(&lexicon, "fünfzig"), "5");
+        assert_eq!(lexicon.tokens("éfünf")[0].start, 2);
+        assert_eq!(digits(&lexicon, "neuneuf"), "919");
+        assert_eq!(digits(&lexicon, ""), "");
+
+        lexicon.add_word("u", '0');
+        assert_eq!(digits(&lexicon, "ux"), "0");
+        assert_eq!(digits(&lexicon, "une"), "01");
+    }
+
+    #[test]
+    fn test_add_words() {
+        let mut lexicon = DigitLexicon::new();
+        lexicon
+            .add_words("# Dutch\neen=1\n\n twee = 2\nnegen=9")
+            .unwrap();
+        assert_eq!(digits(&lexicon, "tweede"), "2");
+
+        let err = lexicon.add_words("drie=3\nvier:4").unwrap_err();
+        assert_eq!(format!("{err:#}"), "line 2: vier:4: expected `word=digit`");
+        assert!(lexicon.add_words("vijf=five").is_err());
+        assert!(lexicon.add_words("=5").is_err());
+    }
+}
@@ -1,58 +1,89 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::stream::{LineSolver, LineSum};
+use aoc_core::{Answer, DayError, Part};
 use clap::Parser;
 use lazy_static::lazy_static;
-use std::cmp;
-use std::collections::{HashMap, HashSet};
+use std::fs;
 use std::path::PathBuf;
 
+pub mod generator;
+pub mod lexicon;
+
+pub use lexicon::{DigitLexicon, Language, Token};
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
 #[derive(Debug, Clone, Default, Parser)]
 pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+    /// Read the input line by line instead of loading it whole
+    #[clap(long)]
+    pub stream: bool,
+    /// Languages of the spelled-out digits read by part 2
+    #[clap(
+        short,
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "english"
+    )]
+    pub language: Vec<Language>,
+    /// File of extra `word=digit` lines for part 2
     #[clap(short, long)]
-    pub input_filename: PathBuf,
+    pub words: Option<PathBuf>,
+    /// Print every line with the digits picked by part 2 (by part 1 with `--part 1`) highlighted
+    #[clap(long)]
+    pub explain: bool,
+}
+
+impl Opts {
+    /// Digit words read by part 2.
+    pub fn lexicon(&self) -> Result<DigitLexicon> {
+        let mut lexicon = DigitLexicon::new();
+        for &language in &self.language {
+            lexicon.add_language(language);
+        }
+        if let Some(path) = &self.words {
+            let words =
+                fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+            lexicon
+                .add_words(&words)
+                .with_context(|| path.display().to_string())?;
+        }
+        Ok(lexicon)
+    }
 }
 
 lazy_static! {
-    static ref MAP_DIGIT: HashMap<&'static str, char> = [
-        ("one", '1'),
-        ("two", '2'),
-        ("three", '3'),
-        ("four", '4'),
-        ("five", '5'),
-        ("six", '6'),
-        ("seven", '7'),
-        ("eight", '8'),
-        ("nine", '9'),
-    ]
-    .iter()
-    .copied()
-    .collect();
-    static ref START_CHARS: HashSet<char> =
-        ['o', 't', 'f', 's', 'e', 'n'].iter().copied().collect();
+    static ref DIGITS: DigitLexicon = DigitLexicon::new();
+    static ref ENGLISH: DigitLexicon = DigitLexicon::builtin(Language::English);
 }
 
+/// Digits picked in a line, with the tokens they were read from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct Calibration {
-    pub first: Option<char>,
-    pub second: Option<char>,
+    pub first: Option<Token>,
+    pub second: Option<Token>,
 }
 
 impl Calibration {
-    fn new() -> Calibration {
-        Calibration {
-            first: None,
-            second: None,
-        }
-    }
-
-    fn update(&mut self, c: char) {
+    fn update(&mut self, token: Token) {
         match self.first {
-            Some(_) => self.second = Some(c),
-            None => (self.first, self.second) = (Some(c), Some(c)),
+            Some(_) => self.second = Some(token),
+            None => (self.first, self.second) = (Some(token), Some(token)),
         }
     }
 
-    fn compute(&self) -> usize {
+    pub fn compute(&self) -> usize {
         match (self.first, self.second) {
-            (Some(first), Some(second)) => vec![first, second]
+            (Some(first), Some(second)) => vec![first.digit, second.digit]
                 .into_iter()
                 .collect::<String>()
                 .parse::<usize>()
@@ -60,29 +91,118 @@ impl Calibration {
             _ => 0,
         }
     }
-}
 
-pub fn compute_calibration(line: &str) -> Result<usize> {
-    let mut cal: Calibration = Calibration::new();
-    let n = line.len();
+    /// `line` with the first token in green, the last one in cyan and their overlap in yellow,
+    /// followed by the value and where each digit comes from.
+    pub fn explain(&self, line: &str) -> String {
+        const COLOURS: [&str; 4] = ["", "\x1b[1;32m", "\x1b[1;36m", "\x1b[1;33m"];
+        const RESET: &str = "\x1b[0m";
+        let within =
+            |token: Option<Token>, i: usize| token.is_some_and(|t| (t.start..t.end).contains(&i));
 
-    for (i, c) in line.chars().enumerate() {
-        if c.is_ascii_digit() {
-            cal.update(c);
-            continue;
-        }
-        let remaining = n - i;
-        if let Some(&_c) = START_CHARS.get(&c) {
-            if remaining >= 3 {
-                for offset in 3..(cmp::min(remaining, 5) + 1) {
-                    if let Some(&c) = MAP_DIGIT.get(&line[i..i + offset]) {
-                        cal.update(c)
-                    }
-                }
+        let mut text = String::new();
+        let mut current = 0;
+        for (i, c) in line.char_indices() {
+            let colour = within(self.first, i) as usize + 2 * (within(self.second, i) as usize);
+            let colour = if colour == 3 && self.first == self.second {
+                1
+            } else {
+                colour
+            };
+            if colour != current {
+                text.push_str(if colour == 0 { RESET } else { COLOURS[colour] });
+                current = colour;
             }
+            text.push(c);
         }
+        if current != 0 {
+            text.push_str(RESET);
+        }
+
+        let source = |token: Token| {
+            let kind = if token.spelled { "spelled" } else { "digit" };
+            format!(
+                "`{}` {kind} at {}..{}",
+                &line[token.start..token.end],
+                token.start,
+                token.end
+            )
+        };
+        match (self.first, self.second) {
+            (Some(first), Some(second)) => format!(
+                "{text} = {} (first {}, last {})",
+                self.compute(),
+                source(first),
+                source(second)
+            ),
+            _ => format!("{text} = 0 (no digit)"),
+        }
+    }
+}
+
+/// Digits picked by part 1 (`lexicon` without words) or part 2.
+pub fn trace_calibration(line: &str, lexicon: &DigitLexicon) -> Calibration {
+    let mut cal = Calibration::default();
+    for token in lexicon.tokens(line) {
+        cal.update(token);
+    }
+    cal
+}
+
+/// Part 1 reads digits only, part 2 also the words of `lexicon`.
+pub fn compute_calibration(line: &str, lexicon: &DigitLexicon) -> usize {
+    trace_calibration(line, lexicon).compute()
+}
+
+fn lexicon(spelled: bool) -> &'static DigitLexicon {
+    if spelled {
+        &ENGLISH
+    } else {
+        &DIGITS
+    }
+}
+
+fn calibrate<'a>(lines: impl Iterator<Item = &'a str>, spelled: bool) -> Result<Answer, DayError> {
+    let lexicon = lexicon(spelled);
+    Ok(lines
+        .map(|line| compute_calibration(line, lexicon))
+        .sum::<usize>()
+        .into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    calibrate(input.lines(), false)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    calibrate(input.lines(), true)
+}
+
+fn line_calibration(line: &str, spelled: bool) -> Result<usize, DayError> {
+    Ok(compute_calibration(line, lexicon(spelled)))
+}
+
+pub fn stream(part: u8) -> Box<dyn LineSolver> {
+    match part {
+        1 => Box::new(LineSum::new(|line| line_calibration(line, false))),
+        _ => Box::new(LineSum::new(|line| line_calibration(line, true))),
     }
-    Ok(cal.compute())
+}
+
+/// Splitting the input in lines is all there is to parse.
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    bench::measure(
+        input,
+        runs,
+        |input| Ok(input.lines().collect::<Vec<&str>>()),
+        |lines| calibrate(lines.into_iter(), part == 2),
+    )
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, |input| {
+        Ok(input.lines().collect::<Vec<&str>>())
+    })
 }
 
 #[cfg(test)]
@@ -95,11 +215,65 @@ mod tests {
             ("sevenfourfour99seven8", 78),
             ("74two24jjsxgvzfqxtwone", 71),
             ("74two24jjsxgvzfqxtwonex", 71),
+            ("éight7", 77),
         ];
-        let output = cases.iter().map(|(line, _)| compute_calibration(line));
+        let output = cases
+            .iter()
+            .map(|(line, _)| compute_calibration(line, &ENGLISH));
 
         for ((_, expected), calibration) in cases.iter().zip(output) {
-            assert_eq!(*expected, calibration.unwrap())
+            assert_eq!(*expected, calibration)
         }
     }
+
+    #[test]
+    fn test_digits_only() {
+        let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        assert_eq!(part1(input), Ok(Answer::Unsigned(142)));
+        assert_eq!(compute_calibration("two1nine", &DIGITS), 11);
+    }
+
+    #[test]
+    fn test_languages() {
+        let mut lexicon = DigitLexicon::builtin(Language::French);
+        lexicon.add_language(Language::Spanish);
+        assert_eq!(compute_calibration("xtroisdeuxcuatrox", &lexicon), 34);
+        let german = DigitLexicon::builtin(Language::German);
+        assert_eq!(compute_calibration("fünfzehnundsiebzig", &german), 55);
+        assert_eq!(compute_calibration("sechsundzwanzig", &ENGLISH), 0);
+    }
+
+    #[test]
+    fn test_stream() {
+        let answers = aoc_core::stream::solve(&[1, 2], INPUT.as_bytes(), stream).unwrap();
+        assert_eq!(answers, vec![part1(INPUT).unwrap(), part2(INPUT).unwrap()]);
+    }
+
+    #[test]
+    fn test_explain() {
+        let cal = trace_calibration("xtwone3", &ENGLISH);
+        assert_eq!(cal.first.map(|t| (t.start, t.spelled)), Some((1, true)));
+        assert_eq!(cal.second.map(|t| (t.start, t.spelled)), Some((6, false)));
+        assert_eq!(
+            cal.explain("xtwone3"),
+            "x\x1b[1;32mtwo\x1b[0mne\x1b[1;36m3\x1b[0m = 23 (first `two` spelled at 1..4, last `3` digit at 6..7)"
+        );
+
+        let cal = trace_calibration("twone", &ENGLISH);
+        assert_eq!(
+            cal.explain("twone"),
+            "\x1b[1;32mtw\x1b[1;33mo\x1b[1;36mne\x1b[0m = 21 (first `two` spelled at 0..3, last `one` spelled at 2..5)"
+        );
+        assert_eq!(
+            trace_calibration("a7b", &DIGITS).explain("a7b"),
+            "a\x1b[1;32m7\x1b[0mb = 77 (first `7` digit at 1..2, last `7` digit at 1..2)"
+        );
+        assert_eq!(
+            trace_calibration("two", &DIGITS).explain("two"),
+            "two = 0 (no digit)"
+        );
+    }
 }
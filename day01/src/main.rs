@@ -1,19 +1,49 @@
 use anyhow::Result;
+use aoc_core::input;
 use clap::Parser;
-use day01::compute_calibration;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use day01::{compute_calibration, trace_calibration, DigitLexicon};
+use std::io::BufRead;
+use std::process::ExitCode;
 
-fn main() -> Result<()> {
+fn main() -> Result<ExitCode> {
     let opts = day01::Opts::parse();
-    let f = File::open(&opts.input_filename)?;
-    let reader = BufReader::new(f);
-    let mut calibration = 0;
+    let (digits, lexicon) = (DigitLexicon::new(), opts.lexicon()?);
+    let (mut part1, mut part2) = (0, 0);
+    let mut add = |i: usize, line: &str| {
+        if opts.explain {
+            let lexicon = if opts.part.runs(2) { &lexicon } else { &digits };
+            println!(
+                "{:>5} {}",
+                i + 1,
+                trace_calibration(line, lexicon).explain(line)
+            );
+        }
+        if opts.part.runs(1) {
+            part1 += compute_calibration(line, &digits);
+        }
+        if opts.part.runs(2) {
+            part2 += compute_calibration(line, &lexicon);
+        }
+    };
 
-    for line in reader.lines() {
-        calibration += compute_calibration(&line?)?;
+    if opts.stream {
+        // only one line is held at a time
+        let reader = input::open(opts.input.as_ref(), day01::INPUT)?;
+        for (i, line) in reader.lines().enumerate() {
+            add(i, &line?);
+        }
+    } else {
+        let input = input::read(opts.input.as_ref(), day01::INPUT)?;
+        for (i, line) in input.lines().enumerate() {
+            add(i, line);
+        }
     }
     // outputs result in terminal
-    println!("{}", calibration);
-    Ok(())
+    if opts.part.runs(1) {
+        println!("Part 1: {}", part1);
+    }
+    if opts.part.runs(2) {
+        println!("Part 2: {}", part2);
+    }
+    Ok(ExitCode::SUCCESS)
 }
@@ -0,0 +1,43 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "games",
+            default: 100,
+            range: 1..=100_000_000,
+            help: "number of games",
+        },
+        Param {
+            name: "draws",
+            default: 6,
+            range: 1..=1000,
+            help: "maximum number of draws in a game",
+        },
+    ],
+    generate,
+};
+
+const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+/// One to three colours in any order, each with 1 to 20 cubes.
+fn draw(rng: &mut Rng) -> String {
+    let mut colours = COLOURS;
+    rng.shuffle(&mut colours);
+    colours[..rng.range(1..=3)]
+        .iter()
+        .map(|colour| format!("{} {colour}", rng.range(1..=20)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let draws = sizes.get("draws");
+    (1..=sizes.get("games"))
+        .map(|id| {
+            let game: Vec<String> = (0..rng.range(1..=draws)).map(|_| draw(rng)).collect();
+            format!("Game {id}: {}", game.join("; "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,49 +1,308 @@
-use anyhow::{anyhow, Result};
+use anyhow::{Context, Result};
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::stream::LineSolver;
+use aoc_core::{Answer, DayError, Part};
 use clap::Parser;
-use regex::Regex;
-use std::cmp;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
 use std::path::PathBuf;
+use std::sync::LazyLock;
+
+pub mod generator;
+pub mod log;
+pub mod rules;
+pub mod stats;
+
+pub use log::{GameLog, LogReader};
+pub use rules::{BagRules, Violation};
+pub use stats::GameStats;
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
 
 #[derive(Debug, Clone, Default, Parser)]
 pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
     #[clap(short, long)]
-    pub input_filename: PathBuf,
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+    /// Read the input line by line instead of loading it whole
+    #[clap(long)]
+    pub stream: bool,
+    /// Cubes in the bag for part 1, as `red=12,green=13,blue=14`
+    #[clap(short, long)]
+    pub bag: Option<BagRules>,
+    /// File of `colour=count` lines giving the bag for part 1
+    #[clap(long, conflicts_with = "bag")]
+    pub rules: Option<PathBuf>,
+    /// Print whether each game is possible with the bag, and why not
+    #[clap(long)]
+    pub report: bool,
+    /// Print figures over all the games: draws and cubes of each colour
+    #[clap(long)]
+    pub stats: bool,
+    /// Fail on malformed lines, and on colours missing from `--bag` or `--rules`, instead of
+    /// skipping them
+    #[clap(long)]
+    pub strict: bool,
+}
+
+impl Opts {
+    /// Bag of part 1, the puzzle's unless one was given.
+    pub fn bag_rules(&self) -> Result<BagRules> {
+        match (&self.bag, &self.rules) {
+            (Some(bag), _) => Ok(bag.clone()),
+            (None, Some(path)) => fs::read_to_string(path)
+                .map_err(anyhow::Error::from)
+                .and_then(|rules| rules.parse())
+                .with_context(|| path.display().to_string()),
+            (None, None) => Ok(BagRules::default()),
+        }
+    }
+
+    /// Reader of the log, only accepting the colours of the bag when one was given.
+    pub fn log_reader(&self) -> Result<LogReader> {
+        let given = self.bag.is_some() || self.rules.is_some();
+        Ok(LogReader {
+            colours: if given { Some(self.bag_rules()?) } else { None },
+            strict: self.strict,
+        })
+    }
 }
 
-#[derive(Debug)]
+static PUZZLE_BAG: LazyLock<BagRules> = LazyLock::new(BagRules::default);
+
+/// Draws of one game, kept in order so that any question can be asked of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Observations {
-    pub max_observed: HashMap<String, usize>,
     pub game_id: usize,
+    /// Cubes of each colour shown by each draw; a colour named twice in a draw adds up.
+    pub draws: Vec<BTreeMap<String, usize>>,
 }
 
 impl Observations {
-    pub fn new(line: &str) -> Result<Observations> {
-        let mut observed: HashMap<String, usize> = HashMap::new();
-        //regex to separate game id from the rest
-        let re1 = Regex::new(r"([0-9]+):(.+)").unwrap();
-        //regex to separate games from each other
-        let re2 = Regex::new(r"[^;]+").unwrap();
-        //regex to obtain results of drawings
-        let re3 = Regex::new(r"([0-9]+) ([a-zA-Z]+)").unwrap();
-
-        let (_, [id, games]) = re1.captures(line).unwrap().extract();
-        let id: usize = id.parse().map_err(|e| anyhow!("Invalid {}: {e}", id))?;
-
-        for game in re2.find_iter(games) {
-            for (_, [number, color]) in re3.captures_iter(game.as_str()).map(|c| c.extract()) {
-                {
-                    let number: usize = number.parse().unwrap();
-                    observed
-                        .entry(color.to_string())
-                        .and_modify(|e| *e = cmp::max(*e, number))
-                        .or_insert(number);
+    pub fn new(line: &str) -> Result<Observations, DayError> {
+        aoc_parse::parse(log::game(None), line).map_err(DayError::from)
+    }
+
+    /// Colours shown in the game, in alphabetical order.
+    pub fn colours(&self) -> BTreeSet<&str> {
+        self.draws
+            .iter()
+            .flat_map(|draw| draw.keys().map(String::as_str))
+            .collect()
+    }
+
+    /// Most cubes of `colour` shown by a draw.
+    pub fn max(&self, colour: &str) -> usize {
+        self.counts(colour).max().unwrap_or(0)
+    }
+
+    /// Most cubes shown by a draw for each colour of the game.
+    pub fn maxima(&self) -> BTreeMap<&str, usize> {
+        self.colours()
+            .into_iter()
+            .map(|colour| (colour, self.max(colour)))
+            .collect()
+    }
+
+    /// Draw, from 1, where `colour` first reached its maximum.
+    pub fn max_draw(&self, colour: &str) -> Option<usize> {
+        let max = self.max(colour);
+        self.counts(colour)
+            .position(|count| count == max && max > 0)
+            .map(|i| i + 1)
+    }
+
+    /// Cubes of `colour` shown over all draws.
+    pub fn total(&self, colour: &str) -> usize {
+        self.counts(colour).sum()
+    }
+
+    /// Cubes of `colour` shown by each draw, 0 when it is not named.
+    fn counts<'a>(&'a self, colour: &'a str) -> impl Iterator<Item = usize> + 'a {
+        self.draws
+            .iter()
+            .map(move |draw| draw.get(colour).copied().unwrap_or(0))
+    }
+}
+
+/// Observations of every game, blank lines aside, failing on the first malformed line.
+pub fn observations(input: &str) -> Result<Vec<Observations>, DayError> {
+    let reader = LogReader {
+        colours: None,
+        strict: true,
+    };
+    reader.read(input).map(|log| log.games)
+}
+
+/// Id of the game if it could be played with the cubes of `bag`, 0 otherwise.
+fn possible_game(obs: &Observations, bag: &BagRules) -> usize {
+    if bag.is_possible(obs) {
+        obs.game_id
+    } else {
+        0
+    }
+}
+
+fn game_power(obs: &Observations, _: &BagRules) -> usize {
+    BagRules::minimum(obs).power()
+}
+
+/// Sum of the ids of the games possible with `bag`.
+pub fn possible_games(observations: &[Observations], bag: &BagRules) -> usize {
+    observations.iter().map(|obs| possible_game(obs, bag)).sum()
+}
+
+/// Sum of the powers of the minimum bags of the games.
+pub fn power(observations: &[Observations]) -> usize {
+    observations
+        .iter()
+        .map(|obs| BagRules::minimum(obs).power())
+        .sum()
+}
+
+/// Part 1 with another bag than the puzzle's.
+pub fn part1_with(input: &str, bag: &BagRules) -> Result<Answer, DayError> {
+    Ok(possible_games(&observations(input)?, bag).into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    part1_with(input, &PUZZLE_BAG)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    Ok(power(&observations(input)?).into())
+}
+
+/// Part adding up a value of each game, one line at a time.
+struct GameSum {
+    reader: LogReader,
+    bag: BagRules,
+    value: fn(&Observations, &BagRules) -> usize,
+    /// Print the lines skipped, which only one part of a run should do.
+    warn: bool,
+    /// Lines fed so far, to position the warnings of skipped lines.
+    line: usize,
+    total: usize,
+}
+
+impl LineSolver for GameSum {
+    fn feed(&mut self, line: &str) -> Result<(), DayError> {
+        self.line += 1;
+        match self.reader.game(line) {
+            Ok(game) => self.total += game.map_or(0, |obs| (self.value)(&obs, &self.bag)),
+            Err(err) if !self.reader.strict => {
+                if self.warn {
+                    eprintln!("skipped {}", err.on_line(self.line).report_line(line))
                 }
             }
+            Err(err) => return Err(err),
         }
-        Ok(Observations {
-            max_observed: observed,
-            game_id: id,
-        })
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<Answer, DayError> {
+        Ok(self.total.into())
+    }
+}
+
+pub fn stream(part: u8) -> Box<dyn LineSolver> {
+    let reader = LogReader {
+        colours: None,
+        strict: true,
+    };
+    stream_with(part, &PUZZLE_BAG, &reader, false)
+}
+
+/// [`stream`] reading lines with `reader`, and with another bag than the puzzle's for part 1.
+/// Lines skipped are printed to stderr when `warn`.
+pub fn stream_with(
+    part: u8,
+    bag: &BagRules,
+    reader: &LogReader,
+    warn: bool,
+) -> Box<dyn LineSolver> {
+    Box::new(GameSum {
+        reader: reader.clone(),
+        bag: bag.clone(),
+        value: if part == 1 { possible_game } else { game_power },
+        warn,
+        line: 0,
+        total: 0,
+    })
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    bench::measure(input, runs, observations, |obs| {
+        Ok(match part {
+            1 => possible_games(&obs, &PUZZLE_BAG),
+            _ => power(&obs),
+        }
+        .into())
+    })
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, observations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(part1(INPUT), Ok(Answer::Unsigned(8)));
+        assert_eq!(part2(INPUT), Ok(Answer::Unsigned(2286)));
+        let bag = BagRules::default().with("red", 20).with("blue", 15);
+        assert_eq!(part1_with(INPUT, &bag), Ok(Answer::Unsigned(15)));
+    }
+
+    #[test]
+    fn test_observations() {
+        let obs = Observations::new(
+            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+        )
+        .unwrap();
+        assert_eq!(obs.game_id, 4);
+        assert_eq!(obs.draws.len(), 3);
+        assert_eq!(obs.draws[1].get("red"), Some(&6));
+        assert_eq!(obs.draws[1].get("blue"), None);
+        assert_eq!(
+            obs.colours().into_iter().collect::<Vec<_>>(),
+            ["blue", "green", "red"]
+        );
+        assert_eq!(obs.max("green"), 3);
+        assert_eq!(obs.max_draw("green"), Some(2));
+        assert_eq!(obs.max_draw("red"), Some(3));
+        assert_eq!(obs.max_draw("violet"), None);
+        assert_eq!(obs.total("blue"), 21);
+        assert_eq!(obs.maxima()["blue"], 15);
+    }
+
+    #[test]
+    fn test_invalid_line() {
+        let input = "Game 1: 3 blue\nGame two: 1 red";
+        let Err(DayError::Parse(err)) = part1(input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn test_stream() {
+        let answers = aoc_core::stream::solve(&[1, 2], INPUT.as_bytes(), stream).unwrap();
+        assert_eq!(answers, vec![Answer::Unsigned(8), Answer::Unsigned(2286)]);
+        let input = "Game 1: 3 blue\nGame two: 1 red";
+        assert!(aoc_core::stream::solve(&[1], input.as_bytes(), stream).is_err());
     }
 }
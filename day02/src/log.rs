@@ -0,0 +1,151 @@
+use aoc_core::DayError;
+use aoc_parse::{blanks, unsigned};
+use combine::{
+    error::StreamError,
+    many1,
+    parser::char::{char, letter, string},
+    sep_by1,
+    stream::StreamErrorFor,
+    ParseError, Parser, Stream,
+};
+use std::collections::BTreeMap;
+
+use crate::{BagRules, Observations};
+
+/// Colour of some cubes, one the bag holds when `bag` is given.
+fn colour<'a, Input>(bag: Option<&'a BagRules>) -> impl Parser<Input, Output = String> + 'a
+where
+    Input: Stream<Token = char> + 'a,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    many1::<String, _, _>(letter()).and_then(move |colour: String| match bag {
+        Some(bag) if !bag.holds(&colour) => Err(StreamErrorFor::<Input>::message_format(
+            format_args!("unknown colour `{colour}`, the bag holds {bag}"),
+        )),
+        _ => Ok(colour),
+    })
+}
+
+/// `3 blue, 4 red`
+fn draw<'a, Input>(
+    bag: Option<&'a BagRules>,
+) -> impl Parser<Input, Output = BTreeMap<String, usize>> + 'a
+where
+    Input: Stream<Token = char> + 'a,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let cubes = (unsigned().skip(char(' ')).skip(blanks()), colour(bag)).skip(blanks());
+    sep_by1(cubes, char(',').skip(blanks())).map(|cubes: Vec<(usize, String)>| {
+        let mut draw = BTreeMap::new();
+        for (count, colour) in cubes {
+            *draw.entry(colour).or_default() += count;
+        }
+        draw
+    })
+}
+
+/// `Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green`
+pub(crate) fn game<'a, Input>(
+    bag: Option<&'a BagRules>,
+) -> impl Parser<Input, Output = Observations> + 'a
+where
+    Input: Stream<Token = char> + 'a,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    (
+        string("Game").skip(char(' ')).skip(blanks()),
+        unsigned(),
+        char(':').skip(blanks()),
+        sep_by1(draw(bag), char(';').skip(blanks())),
+    )
+        .map(|(_, game_id, _, draws)| Observations { game_id, draws })
+}
+
+/// How the lines of a game log are read.
+#[derive(Debug, Clone, Default)]
+pub struct LogReader {
+    /// Colours the games may show, any when `None`.
+    pub colours: Option<BagRules>,
+    /// Fail on the first malformed line instead of skipping it.
+    pub strict: bool,
+}
+
+/// Games of a log, with the errors of the lines skipped.
+#[derive(Debug, Clone, Default)]
+pub struct GameLog {
+    pub games: Vec<Observations>,
+    pub skipped: Vec<DayError>,
+}
+
+impl LogReader {
+    /// Game of a line, `None` when it is blank; errors are positioned on line 1.
+    pub fn game(&self, line: &str) -> Result<Option<Observations>, DayError> {
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+        aoc_parse::parse(game(self.colours.as_ref()), line)
+            .map(Some)
+            .map_err(DayError::from)
+    }
+
+    /// Games of every line, blank lines aside. Malformed lines fail the whole log when strict.
+    pub fn read(&self, input: &str) -> Result<GameLog, DayError> {
+        let mut log = GameLog::default();
+        for (i, line) in input.lines().enumerate() {
+            match self.game(line).map_err(|err| err.on_line(i + 1)) {
+                Ok(game) => log.games.extend(game),
+                Err(err) if !self.strict => log.skipped.push(err),
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(log)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOG: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue
+
+Game 2: 1 blue, 2 violet
+Game 3 8 green
+Game 4: 1 green, 1 green";
+
+    #[test]
+    fn test_read() {
+        let lenient = LogReader::default();
+        let log = lenient.read(LOG).unwrap();
+        assert_eq!(log.games.len(), 3);
+        assert_eq!(log.games[2].draws[0]["green"], 2);
+        let DayError::Parse(err) = &log.skipped[0] else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column), (4, 7));
+
+        let strict = LogReader {
+            strict: true,
+            ..LogReader::default()
+        };
+        assert_eq!(strict.read(LOG).unwrap_err(), log.skipped[0]);
+    }
+
+    #[test]
+    fn test_colours() {
+        let reader = LogReader {
+            colours: Some(BagRules::default()),
+            strict: true,
+        };
+        let Err(DayError::Parse(err)) = reader.read(LOG) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(err.line, 3);
+        assert!(
+            err.message
+                .contains("unknown colour `violet`, the bag holds 14 blue, 13 green, 12 red"),
+            "{}",
+            err.message
+        );
+        assert_eq!(reader.game("Game 5: 2 red").unwrap().unwrap().game_id, 5);
+    }
+}
@@ -1,62 +1,55 @@
 use anyhow::Result;
+use aoc_core::{input, stream};
 use clap::Parser;
-use day02::{self, Observations};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use day02::{BagRules, GameStats, Observations};
+use std::process::ExitCode;
 
-// fn main() -> Result<()> {
-//     let opts = day02::Opts::parse();
-//     let f = File::open(&opts.input_filename)?;
-//     let reader = BufReader::new(f);
-//     let mut out = 0;
-//     let max_vals: HashMap<String, usize> = HashMap::from([
-//         ("blue".to_string(), 14),
-//         ("red".to_string(), 12),
-//         ("green".to_string(), 13),
-//     ]);
-//     let mut ok_game: bool = true;
-//     for line in reader.lines() {
-//         if let Ok(obs) = Observations::new(&line.unwrap()) {
-//             for (key, val) in obs.max_observed.iter() {
-//                 match max_vals.get(key) {
-//                     Some(v) => {
-//                         if v < val {
-//                             ok_game = false;
-//                             break;
-//                         }
-//                     }
-//                     None => {
-//                         ok_game = false;
-//                         break;
-//                     }
-//                 }
-//             }
-//             if ok_game {
-//                 out += obs.game_id;
-//             }
-//             ok_game = true;
-//         };
-//     }
-//     // outputs result in terminal
-//     println!("{}", out);
-//     Ok(())
-// }
+/// Prints whether each game is possible with `bag`, and why not.
+fn report(bag: &BagRules, games: &[Observations]) {
+    println!("Bag: {bag}");
+    for obs in games {
+        let violations = bag.violations(obs);
+        if violations.is_empty() {
+            let power = BagRules::minimum(obs).power();
+            println!("Game {}: possible, minimum bag power {power}", obs.game_id);
+        } else {
+            let reasons: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+            println!("Game {}: impossible, {}", obs.game_id, reasons.join("; "));
+        }
+    }
+}
 
-fn main() -> Result<()> {
+fn main() -> Result<ExitCode> {
     let opts = day02::Opts::parse();
-    let f = File::open(&opts.input_filename)?;
-    let reader = BufReader::new(f);
-    let mut out = 0;
-    for line in reader.lines() {
-        let mut prod = 1;
-        if let Ok(obs) = Observations::new(&line.unwrap()) {
-            for (_, val) in obs.max_observed.iter() {
-                prod *= val;
-            }
+    let (bag, reader) = (opts.bag_rules()?, opts.log_reader()?);
+    if opts.stream {
+        let lines = input::open(opts.input.as_ref(), day02::INPUT)?;
+        let first = if opts.part.runs(1) { 1 } else { 2 };
+        return Ok(stream::run(opts.part, lines, |part| {
+            day02::stream_with(part, &bag, &reader, part == first)
+        }));
+    }
+    let input = input::read(opts.input.as_ref(), day02::INPUT)?;
+    let log = match reader.read(&input) {
+        Ok(log) => log,
+        Err(err) => {
+            eprintln!("{}", err.report(&input));
+            return Ok(ExitCode::FAILURE);
         }
-        out += prod;
+    };
+    for err in &log.skipped {
+        eprintln!("skipped {}", err.report(&input));
+    }
+    if opts.stats {
+        print!("{}", GameStats::new(&log.games));
+    }
+    if opts.report {
+        report(&bag, &log.games);
     }
-    // outputs result in terminal
-    println!("{}", out);
-    Ok(())
+    Ok(aoc_core::run(
+        opts.part,
+        &input,
+        |_| Ok(day02::possible_games(&log.games, &bag).into()),
+        |_| Ok(day02::power(&log.games).into()),
+    ))
 }
@@ -0,0 +1,186 @@
+use anyhow::{anyhow, Error, Result};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Observations;
+
+/// Cubes of each colour in the bag; colours missing from it have no cube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BagRules {
+    limits: BTreeMap<String, usize>,
+}
+
+/// Colour of a game drawn more often than the bag allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub colour: String,
+    pub count: usize,
+    /// Draw of the game, from 1, showing the most cubes of the colour.
+    pub draw: usize,
+    pub limit: usize,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} in draw {} but the bag holds {}",
+            self.count, self.colour, self.draw, self.limit
+        )
+    }
+}
+
+/// The bag of the puzzle: 12 red, 13 green and 14 blue cubes.
+impl Default for BagRules {
+    fn default() -> Self {
+        BagRules::new()
+            .with("red", 12)
+            .with("green", 13)
+            .with("blue", 14)
+    }
+}
+
+impl BagRules {
+    /// Empty bag.
+    pub fn new() -> Self {
+        BagRules {
+            limits: BTreeMap::new(),
+        }
+    }
+
+    pub fn with(mut self, colour: &str, count: usize) -> Self {
+        self.limits.insert(colour.to_string(), count);
+        self
+    }
+
+    /// Whether the bag has a count for `colour`, be it 0.
+    pub fn holds(&self, colour: &str) -> bool {
+        self.limits.contains_key(colour)
+    }
+
+    pub fn limit(&self, colour: &str) -> usize {
+        self.limits.get(colour).copied().unwrap_or(0)
+    }
+
+    /// Smallest bag the game could have been played with.
+    pub fn minimum(obs: &Observations) -> Self {
+        BagRules {
+            limits: obs
+                .maxima()
+                .into_iter()
+                .map(|(colour, count)| (colour.to_string(), count))
+                .collect(),
+        }
+    }
+
+    /// Product of the cubes of each colour.
+    pub fn power(&self) -> usize {
+        self.limits.values().product()
+    }
+
+    /// Colours of the game exceeding the bag, in alphabetical order.
+    pub fn violations(&self, obs: &Observations) -> Vec<Violation> {
+        obs.maxima()
+            .into_iter()
+            .filter(|&(colour, count)| count > self.limit(colour))
+            .map(|(colour, count)| Violation {
+                colour: colour.to_string(),
+                count,
+                draw: obs.max_draw(colour).unwrap_or(0),
+                limit: self.limit(colour),
+            })
+            .collect()
+    }
+
+    pub fn is_possible(&self, obs: &Observations) -> bool {
+        self.violations(obs).is_empty()
+    }
+}
+
+/// `colour=count` entries separated by commas or lines, the latter skipped when blank or starting
+/// with `#`, as in a rules file.
+impl FromStr for BagRules {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut rules = BagRules::new();
+        let entries = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.starts_with('#'))
+            .flat_map(|line| line.split(','))
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty());
+        for entry in entries {
+            // clap only shows the outermost message, so each one is complete
+            let (colour, count) = entry
+                .split_once('=')
+                .ok_or_else(|| anyhow!("`{entry}`: expected `colour=count`"))?;
+            let count = count
+                .trim()
+                .parse()
+                .map_err(|err| anyhow!("`{entry}`: {err}"))?;
+            rules = rules.with(colour.trim(), count);
+        }
+        Ok(rules)
+    }
+}
+
+impl fmt::Display for BagRules {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let limits: Vec<String> = self
+            .limits
+            .iter()
+            .map(|(colour, count)| format!("{count} {colour}"))
+            .collect();
+        write!(f, "{}", limits.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_violations() {
+        let obs = Observations::new(
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        )
+        .unwrap();
+        let bag = BagRules::default();
+        assert_eq!(
+            bag.violations(&obs),
+            vec![Violation {
+                colour: String::from("red"),
+                count: 20,
+                draw: 1,
+                limit: 12
+            }]
+        );
+        assert_eq!(
+            bag.violations(&obs)[0].to_string(),
+            "20 red in draw 1 but the bag holds 12"
+        );
+        assert!(bag.clone().with("red", 20).is_possible(&obs));
+
+        let minimum = BagRules::minimum(&obs);
+        assert_eq!(minimum.to_string(), "6 blue, 13 green, 20 red");
+        assert_eq!(minimum.power(), 1560);
+        assert!(minimum.is_possible(&obs));
+        assert_eq!(BagRules::new().violations(&obs).len(), 3);
+    }
+
+    #[test]
+    fn test_parse() {
+        let bag: BagRules = "red=12, green=13,blue=14".parse().unwrap();
+        assert_eq!(bag, BagRules::default());
+        let bag: BagRules = "# wider bag\nred = 20\n\nviolet=3\n".parse().unwrap();
+        assert_eq!(bag.limit("violet"), 3);
+        assert_eq!(bag.limit("blue"), 0);
+
+        let err = "red=12,blue".parse::<BagRules>().unwrap_err();
+        assert_eq!(format!("{err:#}"), "`blue`: expected `colour=count`");
+        assert!("red=many".parse::<BagRules>().is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::Observations;
+
+/// Figures over the games of a log.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GameStats {
+    pub games: usize,
+    pub draws: usize,
+    /// Cubes of each colour shown over all draws.
+    pub totals: BTreeMap<String, usize>,
+    /// Most cubes of each colour shown by a single draw, with the id of the first game to do so.
+    pub records: BTreeMap<String, (usize, usize)>,
+}
+
+impl GameStats {
+    pub fn new(games: &[Observations]) -> Self {
+        let mut stats = GameStats {
+            games: games.len(),
+            ..Default::default()
+        };
+        for obs in games {
+            stats.draws += obs.draws.len();
+            for (colour, max) in obs.maxima() {
+                *stats.totals.entry(colour.to_string()).or_default() += obs.total(colour);
+                let record = stats.records.entry(colour.to_string()).or_default();
+                if max > record.0 {
+                    *record = (max, obs.game_id);
+                }
+            }
+        }
+        stats
+    }
+
+    /// Draws per game on average.
+    pub fn mean_draws(&self) -> f64 {
+        self.draws as f64 / self.games.max(1) as f64
+    }
+}
+
+impl fmt::Display for GameStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} games, {} draws ({:.2} per game)",
+            self.games,
+            self.draws,
+            self.mean_draws()
+        )?;
+        for (colour, total) in &self.totals {
+            let (max, game) = self.records[colour];
+            writeln!(
+                f,
+                "{colour}: {total} cubes shown, at most {max} in a draw (game {game})"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let games: Vec<Observations> = [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        ]
+        .iter()
+        .map(|line| Observations::new(line).unwrap())
+        .collect();
+        let stats = GameStats::new(&games);
+        assert_eq!((stats.games, stats.draws), (3, 9));
+        assert_eq!(stats.totals["red"], 31);
+        assert_eq!(stats.records["blue"], (6, 1));
+        assert_eq!(stats.records["green"], (13, 3));
+        assert_eq!(
+            stats.to_string().lines().next(),
+            Some("3 games, 9 draws (3.00 per game)")
+        );
+        assert_eq!(GameStats::new(&[]).mean_draws(), 0.0);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Number, Symbol};
+
+/// How the numbers around a gear combine into its ratio.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Aggregate {
+    #[default]
+    Product,
+    Sum,
+    Max,
+}
+
+/// What makes a symbol a gear: its kind and how many numbers touch it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRule {
+    pub symbols: Vec<char>,
+    /// Numbers touching the symbol, exactly.
+    pub neighbours: usize,
+    pub aggregate: Aggregate,
+}
+
+/// The puzzle's gear: a `*` touching exactly two numbers, worth their product.
+impl Default for GearRule {
+    fn default() -> Self {
+        GearRule {
+            symbols: vec!['*'],
+            neighbours: 2,
+            aggregate: Aggregate::Product,
+        }
+    }
+}
+
+impl GearRule {
+    pub fn accepts(&self, symbol: &Symbol) -> bool {
+        self.symbols.contains(&symbol.kind)
+    }
+
+    pub fn ratio(&self, numbers: &[&Number]) -> usize {
+        let values = numbers.iter().map(|number| number.value);
+        match self.aggregate {
+            Aggregate::Product => values.product(),
+            Aggregate::Sum => values.sum(),
+            Aggregate::Max => values.max().unwrap_or(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Schematic;
+
+    #[test]
+    fn test_rules() {
+        let schematic = Schematic::parse(include_str!("./test.txt")).unwrap();
+        let ratios = |rule: &GearRule| -> Vec<usize> {
+            schematic
+                .gears_with(rule)
+                .map(|(_, numbers)| rule.ratio(&numbers))
+                .collect()
+        };
+        assert_eq!(ratios(&GearRule::default()), vec![16345, 451490]);
+
+        let sum = GearRule {
+            aggregate: Aggregate::Sum,
+            ..GearRule::default()
+        };
+        assert_eq!(ratios(&sum), vec![502, 1353]);
+
+        let lonely = GearRule {
+            symbols: vec!['*', '#', '+'],
+            neighbours: 1,
+            aggregate: Aggregate::Max,
+        };
+        assert_eq!(ratios(&lonely), vec![633, 617, 592]);
+    }
+}
@@ -0,0 +1,58 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "width",
+            default: 140,
+            range: 1..=100_000,
+            help: "number of columns",
+        },
+        Param {
+            name: "height",
+            default: 140,
+            range: 1..=100_000,
+            help: "number of rows",
+        },
+    ],
+    generate,
+};
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '%', '=', '-', '&'];
+
+/// Numbers of one to three digits separated by dots and symbols, gears being the most common
+/// symbol as in the official input.
+fn row(rng: &mut Rng, width: usize) -> String {
+    let mut row = String::with_capacity(width);
+    let mut col = 0;
+    while col < width {
+        match rng.below(100) {
+            0..=9 => {
+                let digits = rng.range(1..=3).min(width - col);
+                row.push(char::from(b'0' + rng.range(1..=9) as u8));
+                for _ in 1..digits {
+                    row.push(char::from(b'0' + rng.below(10) as u8));
+                }
+                col += digits;
+                if col < width {
+                    row.push('.');
+                    col += 1;
+                }
+                continue;
+            }
+            10..=11 => row.push('*'),
+            12..=13 => row.push(*rng.choose(&SYMBOLS)),
+            _ => row.push('.'),
+        }
+        col += 1;
+    }
+    row
+}
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let width = sizes.get("width");
+    (0..sizes.get("height"))
+        .map(|_| row(rng, width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,158 @@
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::stream::LineSolver;
+use aoc_core::{Answer, DayError, Part};
+
+pub mod gear;
+pub mod generator;
+pub mod schematic;
+
+pub use gear::{Aggregate, GearRule};
+pub use schematic::{Number, Row, Schematic, Symbol, SymbolStats, WindowSum};
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+    /// Symbols that can be gears in part 2
+    #[clap(long, default_value = "*")]
+    pub gear_symbols: String,
+    /// Numbers a gear touches, exactly
+    #[clap(long, default_value_t = 2)]
+    pub gear_neighbours: usize,
+    /// How the numbers of a gear combine
+    #[clap(long, value_enum, default_value = "product")]
+    pub gear_aggregate: Aggregate,
+    /// Print the symbols and part numbers of each kind of symbol
+    #[clap(long, conflicts_with = "stream")]
+    pub breakdown: bool,
+    /// Read the input line by line instead of loading it whole
+    #[clap(long)]
+    pub stream: bool,
+}
+
+impl Opts {
+    /// Gears of part 2.
+    pub fn gear_rule(&self) -> GearRule {
+        GearRule {
+            symbols: self.gear_symbols.chars().collect(),
+            neighbours: self.gear_neighbours,
+            aggregate: self.gear_aggregate,
+        }
+    }
+}
+
+/// Sum of the part numbers.
+fn part_sum(schematic: &Schematic) -> usize {
+    schematic.part_numbers().map(|number| number.value).sum()
+}
+
+/// Sum of the gear ratios.
+fn gear_ratios(schematic: &Schematic) -> usize {
+    gear_ratios_with(schematic, &GearRule::default())
+}
+
+/// Sum of the ratios of the gears defined by `rule`.
+pub fn gear_ratios_with(schematic: &Schematic, rule: &GearRule) -> usize {
+    schematic
+        .gears_with(rule)
+        .map(|(_, numbers)| rule.ratio(&numbers))
+        .sum()
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    Ok(part_sum(&Schematic::parse(input)?).into())
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    Ok(gear_ratios(&Schematic::parse(input)?).into())
+}
+
+/// Solves the default puzzle three rows at a time.
+pub fn stream(part: u8) -> Box<dyn LineSolver> {
+    stream_with(part, GearRule::default())
+}
+
+/// Solves the puzzle three rows at a time, the gears of part 2 being defined by `rule`.
+pub fn stream_with(part: u8, rule: GearRule) -> Box<dyn LineSolver> {
+    match part {
+        1 => Box::new(WindowSum::new(|rows, middle| {
+            schematic::part_numbers_at(rows, middle)
+                .map(|number| number.value)
+                .sum()
+        })),
+        _ => Box::new(WindowSum::new(move |rows, middle| {
+            schematic::adjacent_at(rows, middle, |symbol| rule.accepts(symbol))
+                .filter(|(_, numbers)| numbers.len() == rule.neighbours)
+                .map(|(_, numbers)| rule.ratio(&numbers))
+                .sum()
+        })),
+    }
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    let solve = if part == 1 { part_sum } else { gear_ratios };
+    bench::measure(input, runs, Schematic::parse, |schematic| {
+        Ok(solve(&schematic).into())
+    })
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, Schematic::parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = include_str!("./test.txt");
+        assert_eq!(part1(input), Ok(Answer::Unsigned(4361)));
+        assert_eq!(part2(input), Ok(Answer::Unsigned(467835)));
+    }
+
+    #[test]
+    fn test_stream() {
+        let answers = aoc_core::stream::solve(&[1, 2], INPUT.as_bytes(), stream).unwrap();
+        assert_eq!(answers, vec![part1(INPUT).unwrap(), part2(INPUT).unwrap()]);
+        let input = include_str!("./test.txt");
+        let answers = aoc_core::stream::solve(&[1, 2], input.as_bytes(), stream).unwrap();
+        assert_eq!(
+            answers,
+            vec![Answer::Unsigned(4361), Answer::Unsigned(467835)]
+        );
+    }
+
+    #[test]
+    fn test_stream_rules() {
+        use clap::Parser;
+
+        let opts = Opts::parse_from([
+            "day03",
+            "--stream",
+            "--gear-aggregate",
+            "sum",
+            "--gear-neighbours",
+            "1",
+            "--gear-symbols",
+            "*#",
+        ]);
+        let rule = opts.gear_rule();
+        let schematic = Schematic::parse(INPUT).unwrap();
+        let batch = gear_ratios_with(&schematic, &rule);
+        let answers = aoc_core::stream::solve(&[2], INPUT.as_bytes(), |part| {
+            stream_with(part, rule.clone())
+        })
+        .unwrap();
+        assert_eq!(answers, vec![Answer::Unsigned(batch)]);
+        assert_ne!(batch, gear_ratios(&schematic));
+        assert!(Opts::try_parse_from(["day03", "--stream", "--breakdown"]).is_err());
+    }
+}
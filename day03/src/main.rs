@@ -0,0 +1,38 @@
+use anyhow::Result;
+use aoc_core::{input, stream};
+use clap::Parser;
+use day03::Schematic;
+use std::process::ExitCode;
+
+fn main() -> Result<ExitCode> {
+    let opts = day03::Opts::parse();
+    if opts.stream {
+        let reader = input::open(opts.input.as_ref(), day03::INPUT)?;
+        return Ok(stream::run(opts.part, reader, |part| {
+            day03::stream_with(part, opts.gear_rule())
+        }));
+    }
+    let input = input::read(opts.input.as_ref(), day03::INPUT)?;
+    let rule = opts.gear_rule();
+    if opts.breakdown {
+        match Schematic::parse(&input) {
+            Ok(schematic) => {
+                println!("symbol  count  part numbers  sum");
+                for (kind, stats) in schematic.breakdown() {
+                    println!(
+                        "{kind:<6}  {:>5}  {:>12}  {}",
+                        stats.symbols, stats.numbers, stats.sum
+                    );
+                }
+            }
+            Err(err) => {
+                eprintln!("{}", err.report(&input));
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+    }
+    Ok(aoc_core::run(opts.part, &input, day03::part1, |input| {
+        let schematic = Schematic::parse(input)?;
+        Ok(day03::gear_ratios_with(&schematic, &rule).into())
+    }))
+}
@@ -0,0 +1,322 @@
+use aoc_core::stream::LineSolver;
+use aoc_core::{Answer, DayError};
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+use crate::GearRule;
+
+/// Number of the schematic, written on `row` over the columns `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+    pub value: usize,
+}
+
+impl Number {
+    /// Whether the number touches the cell, diagonally included.
+    pub fn touches(&self, row: usize, col: usize) -> bool {
+        self.row.abs_diff(row) <= 1 && self.start <= col + 1 && col <= self.end
+    }
+}
+
+/// Any character but a digit or `.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub row: usize,
+    pub col: usize,
+    pub kind: char,
+}
+
+/// Numbers and symbols of one row, each sorted by column so that the ones near a column are
+/// found by binary search.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Row {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+}
+
+impl Row {
+    /// Row `row` of the schematic; errors are positioned on line 1.
+    pub fn parse(row: usize, line: &str) -> Result<Self, DayError> {
+        let (mut numbers, mut symbols) = (Vec::new(), Vec::new());
+        let mut digits: Option<(usize, String)> = None;
+        // columns count characters, not bytes; a sentinel dot closes a number ending the line
+        for (col, c) in line.chars().chain(['.']).enumerate() {
+            if c.is_ascii_digit() {
+                digits.get_or_insert((col, String::new())).1.push(c);
+                continue;
+            }
+            if let Some((start, text)) = digits.take() {
+                let value = text
+                    .parse::<usize>()
+                    .map_err(|e| DayError::at(1, start + 1, e.to_string()))?;
+                numbers.push(Number {
+                    row,
+                    start,
+                    end: col,
+                    value,
+                });
+            }
+            if c != '.' {
+                symbols.push(Symbol { row, col, kind: c });
+            }
+        }
+        Ok(Row { numbers, symbols })
+    }
+
+    /// Symbols in the columns `start..=end`.
+    fn symbols_between(&self, start: usize, end: usize) -> &[Symbol] {
+        let from = self.symbols.partition_point(|s| s.col < start);
+        let to = self.symbols.partition_point(|s| s.col <= end);
+        &self.symbols[from..to.max(from)]
+    }
+
+    /// Numbers touching column `col`, numbers being disjoint and thus sorted by end too.
+    fn numbers_touching(&self, col: usize) -> &[Number] {
+        let from = self.numbers.partition_point(|n| n.end < col);
+        let to = self.numbers.partition_point(|n| n.start <= col + 1);
+        &self.numbers[from..to.max(from)]
+    }
+}
+
+/// Rows of `rows` around `middle`, itself included.
+fn around(rows: &[Row], middle: usize) -> &[Row] {
+    &rows[middle.saturating_sub(1)..(middle + 2).min(rows.len())]
+}
+
+/// Numbers of row `middle` touching a symbol of `rows`.
+pub(crate) fn part_numbers_at(rows: &[Row], middle: usize) -> impl Iterator<Item = &Number> {
+    rows[middle].numbers.iter().filter(move |number| {
+        around(rows, middle).iter().any(|row| {
+            !row.symbols_between(number.start.saturating_sub(1), number.end)
+                .is_empty()
+        })
+    })
+}
+
+/// Symbols of row `middle` accepted by `filter`, with the numbers of `rows` touching them.
+pub(crate) fn adjacent_at<'a>(
+    rows: &'a [Row],
+    middle: usize,
+    filter: impl Fn(&Symbol) -> bool + 'a,
+) -> impl Iterator<Item = (&'a Symbol, Vec<&'a Number>)> + 'a {
+    rows[middle]
+        .symbols
+        .iter()
+        .filter(move |s| filter(s))
+        .map(move |symbol| {
+            let numbers = around(rows, middle)
+                .iter()
+                .flat_map(|row| row.numbers_touching(symbol.col))
+                .collect();
+            (symbol, numbers)
+        })
+}
+
+/// Numbers and symbols of an engine schematic, row by row.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schematic {
+    rows: Vec<Row>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Self, DayError> {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Row::parse(i, line).map_err(|err| err.on_line(i + 1)))
+            .collect::<Result<_, _>>()?;
+        Ok(Schematic { rows })
+    }
+
+    pub fn numbers(&self) -> impl Iterator<Item = &Number> {
+        self.rows.iter().flat_map(|row| &row.numbers)
+    }
+
+    pub fn symbols(&self) -> impl Iterator<Item = &Symbol> {
+        self.rows.iter().flat_map(|row| &row.symbols)
+    }
+
+    /// Every symbol accepted by `filter`, with the numbers touching it.
+    pub fn adjacent<'a>(
+        &'a self,
+        filter: impl Fn(&Symbol) -> bool + Copy + 'a,
+    ) -> impl Iterator<Item = (&'a Symbol, Vec<&'a Number>)> + 'a {
+        (0..self.rows.len()).flat_map(move |middle| adjacent_at(&self.rows, middle, filter))
+    }
+
+    /// Numbers touching at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        (0..self.rows.len()).flat_map(|middle| part_numbers_at(&self.rows, middle))
+    }
+
+    /// Every `*` touching exactly two numbers, with them.
+    pub fn gears(&self) -> impl Iterator<Item = (&Symbol, [&Number; 2])> {
+        self.adjacent(|symbol| symbol.kind == '*')
+            .filter_map(|(symbol, numbers)| match numbers[..] {
+                [first, second] => Some((symbol, [first, second])),
+                _ => None,
+            })
+    }
+
+    /// Every gear as defined by `rule`, with its numbers.
+    pub fn gears_with<'a>(
+        &'a self,
+        rule: &'a GearRule,
+    ) -> impl Iterator<Item = (&'a Symbol, Vec<&'a Number>)> + 'a {
+        self.adjacent(|symbol| rule.accepts(symbol))
+            .filter(|(_, numbers)| numbers.len() == rule.neighbours)
+    }
+
+    /// Symbols and part numbers of each kind of symbol; a number touching symbols of several
+    /// kinds counts for each of them, but once per kind.
+    pub fn breakdown(&self) -> BTreeMap<char, SymbolStats> {
+        let mut breakdown: BTreeMap<char, SymbolStats> = BTreeMap::new();
+        let mut counted: HashSet<(char, usize, usize)> = HashSet::new();
+        for (symbol, numbers) in self.adjacent(|_| true) {
+            let stats = breakdown.entry(symbol.kind).or_default();
+            stats.symbols += 1;
+            for number in numbers {
+                if counted.insert((symbol.kind, number.row, number.start)) {
+                    stats.numbers += 1;
+                    stats.sum += number.value;
+                }
+            }
+        }
+        breakdown
+    }
+}
+
+/// Value of the row `middle` of `rows`.
+type RowValue = Box<dyn Fn(&[Row], usize) -> usize>;
+
+/// Part adding up a value of each row, judged once the rows around it are known; only three rows
+/// are held at a time.
+pub struct WindowSum {
+    value: RowValue,
+    window: VecDeque<Row>,
+    rows: usize,
+    total: usize,
+}
+
+impl WindowSum {
+    pub fn new(value: impl Fn(&[Row], usize) -> usize + 'static) -> Self {
+        WindowSum {
+            value: Box::new(value),
+            window: VecDeque::with_capacity(3),
+            rows: 0,
+            total: 0,
+        }
+    }
+
+    fn judge(&mut self, middle: usize) {
+        self.total += (self.value)(self.window.make_contiguous(), middle);
+    }
+}
+
+impl LineSolver for WindowSum {
+    fn feed(&mut self, line: &str) -> Result<(), DayError> {
+        if self.window.len() == 3 {
+            self.window.pop_front();
+        }
+        self.window.push_back(Row::parse(self.rows, line)?);
+        self.rows += 1;
+        // the row before the new one has all its neighbours
+        if self.window.len() >= 2 {
+            self.judge(self.window.len() - 2);
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<Answer, DayError> {
+        if !self.window.is_empty() {
+            self.judge(self.window.len() - 1);
+        }
+        Ok(self.total.into())
+    }
+}
+
+/// Figures of one kind of symbol.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SymbolStats {
+    pub symbols: usize,
+    /// Part numbers touching a symbol of the kind.
+    pub numbers: usize,
+    pub sum: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let schematic = Schematic::parse("467..114\n...*....\n..35+.12").unwrap();
+        let values: Vec<usize> = schematic.numbers().map(|n| n.value).collect();
+        assert_eq!(values, vec![467, 114, 35, 12]);
+        assert_eq!(
+            schematic.numbers().last(),
+            Some(&Number {
+                row: 2,
+                start: 6,
+                end: 8,
+                value: 12
+            })
+        );
+        let kinds: String = schematic.symbols().map(|s| s.kind).collect();
+        assert_eq!(kinds, "*+");
+
+        let err = Schematic::parse("..1\n.99999999999999999999999").unwrap_err();
+        assert_eq!(
+            err,
+            DayError::at(2, 2, "number too large to fit in target type")
+        );
+    }
+
+    #[test]
+    fn test_unicode() {
+        let schematic = Schematic::parse("é.12\n...€\n..7.").unwrap();
+        let kinds: Vec<(usize, char)> = schematic.symbols().map(|s| (s.col, s.kind)).collect();
+        assert_eq!(kinds, vec![(0, 'é'), (3, '€')]);
+        let parts: Vec<usize> = schematic.part_numbers().map(|n| n.value).collect();
+        assert_eq!(parts, vec![12, 7]);
+        assert_eq!(schematic.numbers().next().unwrap().start, 2);
+    }
+
+    #[test]
+    fn test_adjacent() {
+        let schematic = Schematic::parse(include_str!("./test.txt")).unwrap();
+        let parts: Vec<usize> = schematic.part_numbers().map(|n| n.value).collect();
+        assert!(!parts.contains(&114) && !parts.contains(&58));
+        assert_eq!(parts.len(), 8);
+
+        let gears: Vec<usize> = schematic
+            .gears()
+            .map(|(_, [a, b])| a.value * b.value)
+            .collect();
+        assert_eq!(gears, vec![16345, 451490]);
+
+        let (star, numbers) = schematic.adjacent(|s| s.kind == '*').nth(1).unwrap();
+        assert_eq!((star.row, star.col), (4, 3));
+        assert_eq!(numbers.len(), 1);
+        assert_eq!(schematic.adjacent(|s| s.kind == '#').count(), 1);
+    }
+
+    #[test]
+    fn test_breakdown() {
+        let schematic = Schematic::parse("1.2.\n.*..\n3$*4").unwrap();
+        let breakdown = schematic.breakdown();
+        assert_eq!(
+            breakdown[&'*'],
+            SymbolStats {
+                symbols: 2,
+                numbers: 4,
+                sum: 10
+            }
+        );
+        assert_eq!(breakdown[&'$'].numbers, 1);
+        let total: usize = schematic.part_numbers().map(|n| n.value).sum();
+        assert_eq!(total, 10);
+    }
+}
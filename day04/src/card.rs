@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use aoc_core::DayError;
+use aoc_parse::{list, unsigned};
+use combine::{
+    parser::char::{char, spaces, string},
+    ParseError, Parser, Stream,
+};
+
+use crate::{Doubling, ScoringRule};
+
+/// Card of the pile, numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scratchcard {
+    pub number: usize,
+    pub winning: HashSet<usize>,
+    pub owned: HashSet<usize>,
+}
+
+impl Scratchcard {
+    /// Owned numbers that are winning.
+    pub fn matches(&self) -> usize {
+        self.winning.intersection(&self.owned).count()
+    }
+
+    /// Score of part 1.
+    pub fn points(&self) -> usize {
+        self.points_with(&Doubling)
+    }
+
+    pub fn points_with(&self, rule: &dyn ScoringRule) -> usize {
+        rule.points(self.matches())
+    }
+
+    /// Numbers of the cards of which each instance of this one wins a copy, in part 2.
+    pub fn wins(&self) -> RangeInclusive<usize> {
+        self.wins_with(&Doubling)
+    }
+
+    pub fn wins_with(&self, rule: &dyn ScoringRule) -> RangeInclusive<usize> {
+        self.number + 1..=self.number + rule.copies_won(self.matches())
+    }
+
+    /// Card of a line; errors are positioned on line 1.
+    pub fn parse(line: &str) -> Result<Self, DayError> {
+        aoc_parse::parse(scratchcard(), line).map_err(DayError::from)
+    }
+}
+
+/// Checks the cards `line` by `line`, those being numbered in order from 1.
+#[derive(Debug, Clone, Default)]
+pub struct Numbering {
+    read: usize,
+}
+
+impl Numbering {
+    /// Card of the next line, `None` when it is blank, failing unless it is numbered after the
+    /// previous card.
+    pub fn next(&mut self, line: &str) -> Result<Option<Scratchcard>, DayError> {
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+        let card = Scratchcard::parse(line)?;
+        let expected = self.read + 1;
+        if card.number != expected {
+            let column = line.find(|c: char| c.is_ascii_digit()).unwrap_or(0) + 1;
+            return Err(DayError::at(
+                1,
+                column,
+                format!("expected card {expected}, found card {}", card.number),
+            ));
+        }
+        self.read = expected;
+        Ok(Some(card))
+    }
+}
+
+/// Cards of every line, blank lines aside, numbered in order from 1.
+pub fn parse(input: &str) -> Result<Vec<Scratchcard>, DayError> {
+    let mut numbering = Numbering::default();
+    let mut cards = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        cards.extend(numbering.next(line).map_err(|err| err.on_line(i + 1))?);
+    }
+    Ok(cards)
+}
+
+fn numbers<Input>() -> impl Parser<Input, Output = HashSet<usize>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    list(unsigned()).map(HashSet::from_iter)
+}
+
+/// `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53`
+fn scratchcard<Input>() -> impl Parser<Input, Output = Scratchcard>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    (
+        string("Card").skip(spaces()),
+        unsigned(),
+        char(':').skip(spaces()),
+        numbers(),
+        char('|').skip(spaces()),
+        numbers(),
+    )
+        .map(|(_, number, _, winning, _, owned)| Scratchcard {
+            number,
+            winning,
+            owned,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let card = Scratchcard::parse("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+        assert_eq!(card.number, 1);
+        assert_eq!(card.winning, HashSet::from([41, 48, 83, 86, 17]));
+        assert_eq!((card.matches(), card.points()), (4, 8));
+        assert_eq!(card.wins(), 2..=5);
+
+        let cards = parse(include_str!("./test.txt")).unwrap();
+        let points: Vec<usize> = cards.iter().map(Scratchcard::points).collect();
+        assert_eq!(points, vec![8, 2, 2, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_numbering() {
+        let err = parse("Card 1: 1 | 1\nCard  3: 2 | 3").unwrap_err();
+        assert_eq!(err, DayError::at(2, 7, "expected card 2, found card 3"));
+        assert!(parse("Card 2: 1 | 1").is_err());
+        assert!(parse("Card 1: 1 | 1\n\nCard 2: 1 | 1\n").is_ok());
+    }
+}
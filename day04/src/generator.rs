@@ -0,0 +1,61 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "cards",
+            default: 200,
+            range: 1..=100_000_000,
+            help: "number of cards",
+        },
+        Param {
+            name: "winning",
+            default: 10,
+            range: 1..=30,
+            help: "winning numbers per card",
+        },
+        Param {
+            name: "owned",
+            default: 25,
+            range: 1..=60,
+            help: "numbers you have per card",
+        },
+    ],
+    generate,
+};
+
+fn numbers(numbers: &[usize]) -> String {
+    numbers
+        .iter()
+        .map(|n| format!("{n:>2}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Most cards win nothing and the others few copies, so that the number of copies of part 2 stays
+/// far from overflowing however many cards there are.
+fn card(rng: &mut Rng, winning: usize, owned: usize) -> (Vec<usize>, Vec<usize>) {
+    let mut pool: Vec<usize> = (1..=99).collect();
+    rng.shuffle(&mut pool);
+    let matches = match rng.chance(70) {
+        true => 0,
+        false => rng.range(1..=4).min(winning).min(owned),
+    };
+    let (wins, others) = pool.split_at(winning);
+    let mut mine: Vec<usize> = wins[..matches].to_vec();
+    mine.extend_from_slice(&others[..owned - matches]);
+    rng.shuffle(&mut mine);
+    (wins.to_vec(), mine)
+}
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let cards = sizes.get("cards");
+    let width = cards.to_string().len();
+    (1..=cards)
+        .map(|id| {
+            let (wins, mine) = card(rng, sizes.get("winning"), sizes.get("owned"));
+            format!("Card {id:>width$}: {} | {}", numbers(&wins), numbers(&mine))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
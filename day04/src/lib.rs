@@ -0,0 +1,100 @@
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::stream::LineSolver;
+use aoc_core::{Answer, DayError, Part};
+
+pub mod card;
+pub mod generator;
+pub mod pile;
+pub mod scoring;
+
+pub use card::{Numbering, Scratchcard};
+pub use pile::{CardEntry, ScratchcardPile};
+pub use scoring::{Cascade, Doubling, Fibonacci, Linear, Scoring, ScoringRule};
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+    /// Read the input line by line instead of loading it whole
+    #[clap(long)]
+    pub stream: bool,
+    /// Print every card with its matches, points and the copies it won, instead of the answers
+    #[clap(long, value_enum)]
+    pub ledger: Option<LedgerFormat>,
+    /// Points of a card for its matches
+    #[clap(long, value_enum, default_value = "doubling")]
+    pub scoring: Scoring,
+    /// Most following cards a card wins copies of, however many matches it has
+    #[clap(long)]
+    pub window: Option<usize>,
+}
+
+impl Opts {
+    pub fn scoring_rule(&self) -> Box<dyn ScoringRule> {
+        match self.window {
+            Some(window) => Box::new(Cascade {
+                scoring: self.scoring,
+                window,
+            }),
+            None => Box::new(self.scoring),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LedgerFormat {
+    Csv,
+    Json,
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    part1_with(input, &Doubling)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    part2_with(input, &Doubling)
+}
+
+/// Points of the cards scored by `rule`.
+pub fn part1_with(input: &str, rule: &dyn ScoringRule) -> Result<Answer, DayError> {
+    Ok(ScratchcardPile::parse_with(input, rule)?.points().into())
+}
+
+/// Cards held once the copies won under `rule` are in.
+pub fn part2_with(input: &str, rule: &dyn ScoringRule) -> Result<Answer, DayError> {
+    Ok(ScratchcardPile::parse_with(input, rule)?.total().into())
+}
+
+pub fn stream(part: u8) -> Box<dyn LineSolver> {
+    stream_with(part, Box::new(Doubling))
+}
+
+pub fn stream_with(part: u8, rule: Box<dyn ScoringRule>) -> Box<dyn LineSolver> {
+    match part {
+        1 => Box::new(pile::Points::new(rule)),
+        _ => Box::new(pile::Copies::new(rule)),
+    }
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    bench::measure(input, runs, card::parse, |cards| {
+        let pile = ScratchcardPile::new(&cards);
+        Ok(if part == 1 {
+            pile.points()
+        } else {
+            pile.total()
+        }
+        .into())
+    })
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, card::parse)
+}
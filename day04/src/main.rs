@@ -0,0 +1,37 @@
+use anyhow::Result;
+use aoc_core::{input, stream};
+use clap::Parser;
+use day04::LedgerFormat;
+use std::process::ExitCode;
+
+fn main() -> Result<ExitCode> {
+    let opts = day04::Opts::parse();
+    if opts.stream {
+        let reader = input::open(opts.input.as_ref(), day04::INPUT)?;
+        return Ok(stream::run(opts.part, reader, |part| {
+            day04::stream_with(part, opts.scoring_rule())
+        }));
+    }
+    let input = input::read(opts.input.as_ref(), day04::INPUT)?;
+    let rule = opts.scoring_rule();
+    if let Some(format) = opts.ledger {
+        let pile = match day04::ScratchcardPile::parse_with(&input, &*rule) {
+            Ok(pile) => pile,
+            Err(err) => {
+                eprintln!("{}", err.report(&input));
+                return Ok(ExitCode::FAILURE);
+            }
+        };
+        match format {
+            LedgerFormat::Csv => print!("{}", pile.to_csv()),
+            LedgerFormat::Json => println!("{}", pile.to_json()),
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+    Ok(aoc_core::run(
+        opts.part,
+        &input,
+        |input| day04::part1_with(input, &*rule),
+        |input| day04::part2_with(input, &*rule),
+    ))
+}
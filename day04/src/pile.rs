@@ -0,0 +1,292 @@
+use aoc_core::stream::LineSolver;
+use aoc_core::{Answer, DayError};
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Write;
+
+use crate::card::{self, Numbering};
+use crate::{Doubling, ScoringRule, Scratchcard};
+
+/// What happened to one card of the pile.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CardEntry {
+    /// Number of the card, from 1.
+    pub card: usize,
+    pub matches: usize,
+    /// Score of the card in part 1.
+    pub points: usize,
+    /// Instances of the card, the original included.
+    pub copies: usize,
+    /// Copies won from each earlier card, by card number.
+    pub won_from: BTreeMap<usize, usize>,
+}
+
+/// Scratchcards with the copies they win from each other.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ScratchcardPile {
+    pub cards: Vec<CardEntry>,
+}
+
+impl ScratchcardPile {
+    pub fn parse(input: &str) -> Result<Self, DayError> {
+        Self::parse_with(input, &Doubling)
+    }
+
+    pub fn parse_with(input: &str, rule: &dyn ScoringRule) -> Result<Self, DayError> {
+        card::parse(input).map(|cards| Self::new_with(&cards, rule))
+    }
+
+    pub fn new(cards: &[Scratchcard]) -> Self {
+        Self::new_with(cards, &Doubling)
+    }
+
+    /// Pile of cards scored by `rule`, each winning copies of the cards following it in `cards`
+    /// whatever their numbers; copies of cards past the last one are lost.
+    pub fn new_with(cards: &[Scratchcard], rule: &dyn ScoringRule) -> Self {
+        let mut entries: Vec<CardEntry> = cards
+            .iter()
+            .map(|card| CardEntry {
+                card: card.number,
+                matches: card.matches(),
+                points: card.points_with(rule),
+                ..Default::default()
+            })
+            .collect();
+        for (i, card) in cards.iter().enumerate() {
+            let copies = 1 + entries[i].won_from.values().sum::<usize>();
+            entries[i].copies = copies;
+            let last = (i + rule.copies_won(card.matches())).min(entries.len() - 1);
+            for next in &mut entries[i + 1..=last] {
+                next.won_from.insert(card.number, copies);
+            }
+        }
+        ScratchcardPile { cards: entries }
+    }
+
+    pub fn card(&self, number: usize) -> Option<&CardEntry> {
+        self.cards.iter().find(|card| card.card == number)
+    }
+
+    /// Cards held at the end, copies included.
+    pub fn total(&self) -> usize {
+        self.cards.iter().map(|card| card.copies).sum()
+    }
+
+    /// Points of every card, up to `usize::MAX`.
+    pub fn points(&self) -> usize {
+        self.cards
+            .iter()
+            .fold(0, |total, card| total.saturating_add(card.points))
+    }
+
+    /// One line per card after a header, `won_from` as space separated `card:copies` pairs.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("card,matches,points,copies,won_from\n");
+        for card in &self.cards {
+            let won_from: Vec<String> = card
+                .won_from
+                .iter()
+                .map(|(from, copies)| format!("{from}:{copies}"))
+                .collect();
+            writeln!(
+                csv,
+                "{},{},{},{},{}",
+                card.card,
+                card.matches,
+                card.points,
+                card.copies,
+                won_from.join(" ")
+            )
+            .unwrap();
+        }
+        csv
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a pile always serializes")
+    }
+}
+
+/// Streaming part 1.
+pub(crate) struct Points {
+    numbering: Numbering,
+    rule: Box<dyn ScoringRule>,
+    total: usize,
+}
+
+impl Points {
+    pub(crate) fn new(rule: Box<dyn ScoringRule>) -> Self {
+        Points {
+            numbering: Numbering::default(),
+            rule,
+            total: 0,
+        }
+    }
+}
+
+impl LineSolver for Points {
+    fn feed(&mut self, line: &str) -> Result<(), DayError> {
+        let Some(card) = self.numbering.next(line)? else {
+            return Ok(());
+        };
+        let points = card.points_with(&*self.rule);
+        self.total = self.total.saturating_add(points);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<Answer, DayError> {
+        Ok(self.total.into())
+    }
+}
+
+/// Streaming part 2, the copies won for the next cards being queued, the front for the card about
+/// to be read. A card only wins copies of the few cards after it, so the queue never grows past
+/// the size of a card.
+pub(crate) struct Copies {
+    numbering: Numbering,
+    rule: Box<dyn ScoringRule>,
+    pending: VecDeque<usize>,
+    total: usize,
+}
+
+impl Copies {
+    pub(crate) fn new(rule: Box<dyn ScoringRule>) -> Self {
+        Copies {
+            numbering: Numbering::default(),
+            rule,
+            pending: VecDeque::new(),
+            total: 0,
+        }
+    }
+}
+
+impl LineSolver for Copies {
+    fn feed(&mut self, line: &str) -> Result<(), DayError> {
+        let Some(card) = self.numbering.next(line)? else {
+            return Ok(());
+        };
+        let copies = 1 + self.pending.pop_front().unwrap_or(0);
+        self.total += copies;
+        for j in 0..self.rule.copies_won(card.matches()) {
+            match self.pending.get_mut(j) {
+                Some(pending) => *pending += copies,
+                None => self.pending.push_back(copies),
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<Answer, DayError> {
+        Ok(self.total.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cascade, Fibonacci, Linear};
+
+    #[test]
+    fn test_pile() {
+        let pile = ScratchcardPile::parse(include_str!("./test.txt")).unwrap();
+        assert_eq!(pile.total(), 30);
+        assert_eq!(pile.points(), 13);
+        let copies: Vec<usize> = pile.cards.iter().map(|card| card.copies).collect();
+        assert_eq!(copies, vec![1, 2, 4, 8, 14, 1]);
+        let fourth = pile.card(4).unwrap();
+        assert_eq!(fourth.won_from, BTreeMap::from([(1, 1), (2, 2), (3, 4)]));
+        assert_eq!(pile.card(0), None);
+
+        let csv = pile.to_csv();
+        assert_eq!(csv.lines().nth(4), Some("4,1,1,8,1:1 2:2 3:4"));
+        assert_eq!(csv.lines().last(), Some("6,0,0,1,"));
+        let json: serde_json::Value = serde_json::from_str(&pile.to_json()).unwrap();
+        assert_eq!(json["cards"][3]["won_from"]["3"], 4);
+    }
+
+    #[test]
+    fn test_overflowing_matches() {
+        let pile = ScratchcardPile::parse("Card 1: 1 2 3 | 1 2 3\nCard 2: 4 | 5").unwrap();
+        assert_eq!(pile.total(), 3);
+        assert_eq!(ScratchcardPile::new(&[]).total(), 0);
+    }
+
+    #[test]
+    fn test_long_card() {
+        let numbers: Vec<String> = (1..=70).map(|n| n.to_string()).collect();
+        let numbers = numbers.join(" ");
+        let input = format!("Card 1: {numbers} | {numbers}\nCard 2: 1 | 1");
+        let pile = ScratchcardPile::parse(&input).unwrap();
+        assert_eq!(pile.card(1).unwrap().points, usize::MAX);
+        assert_eq!(pile.points(), usize::MAX);
+        let fibonacci = ScratchcardPile::parse_with(&input, &Fibonacci).unwrap();
+        assert_eq!(fibonacci.card(1).unwrap().points, 308061521170129);
+
+        let mut points = Points::new(Box::new(Doubling));
+        for line in input.lines() {
+            points.feed(line).unwrap();
+        }
+        assert_eq!(points.finish(), Ok(Answer::Unsigned(usize::MAX)));
+    }
+
+    #[test]
+    fn test_rules() {
+        let input = include_str!("./test.txt");
+        let linear = ScratchcardPile::parse_with(input, &Linear).unwrap();
+        assert_eq!((linear.points(), linear.total()), (9, 30));
+        let capped = Cascade {
+            scoring: Fibonacci,
+            window: 1,
+        };
+        let pile = ScratchcardPile::parse_with(input, &capped).unwrap();
+        assert_eq!(pile.points(), 5 + 2 + 2 + 1);
+        assert_eq!(pile.total(), 1 + 2 + 3 + 4 + 5 + 1);
+
+        let mut copies = Copies::new(Box::new(capped));
+        for line in input.lines() {
+            copies.feed(line).unwrap();
+        }
+        assert_eq!(copies.finish(), Ok(Answer::Unsigned(pile.total())));
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut points = Points::new(Box::new(Doubling));
+        let mut copies = Copies::new(Box::new(Doubling));
+        for line in include_str!("./test.txt").lines() {
+            points.feed(line).unwrap();
+            copies.feed(line).unwrap();
+        }
+        assert_eq!(points.finish(), Ok(Answer::Unsigned(13)));
+        assert_eq!(copies.finish(), Ok(Answer::Unsigned(30)));
+        assert!(Copies::new(Box::new(Doubling))
+            .feed("Card 2: 1 | 1")
+            .is_err());
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let input = "Card 1: 1 2 | 1 2\n\nCard 2: 3 | 3\n  \nCard 3: 4 | 5\n";
+        let answers = aoc_core::stream::solve(&[1, 2], input.as_bytes(), crate::stream).unwrap();
+        assert_eq!(
+            answers,
+            vec![crate::part1(input).unwrap(), crate::part2(input).unwrap()]
+        );
+        assert_eq!(answers, vec![Answer::Unsigned(3), Answer::Unsigned(7)]);
+    }
+
+    #[test]
+    fn test_any_numbers() {
+        let cards: Vec<Scratchcard> = ["Card 7: 1 2 | 1 2", "Card 3: 3 | 3", "Card 9: 4 | 5"]
+            .iter()
+            .map(|line| Scratchcard::parse(line).unwrap())
+            .collect();
+        let pile = ScratchcardPile::new(&cards);
+        assert_eq!(pile.total(), 1 + 2 + 4);
+        assert_eq!(
+            pile.card(9).unwrap().won_from,
+            BTreeMap::from([(7, 1), (3, 2)])
+        );
+        assert_eq!(pile.card(1), None);
+    }
+}
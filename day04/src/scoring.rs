@@ -0,0 +1,115 @@
+/// How a card scores its matches, and how many copies of the next cards it wins.
+pub trait ScoringRule {
+    /// Points of a card with `matches` winning numbers.
+    fn points(&self, matches: usize) -> usize;
+
+    /// Following cards of which each instance of a card with `matches` winning numbers wins a
+    /// copy.
+    fn copies_won(&self, matches: usize) -> usize {
+        matches
+    }
+}
+
+/// The puzzle's scoring: 1 point for the first match, doubled for every other, up to `usize::MAX`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Doubling;
+
+impl ScoringRule for Doubling {
+    fn points(&self, matches: usize) -> usize {
+        match matches {
+            0 => 0,
+            m => u32::try_from(m - 1)
+                .ok()
+                .and_then(|shift| 1usize.checked_shl(shift))
+                .unwrap_or(usize::MAX),
+        }
+    }
+}
+
+/// 1 point per match.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Linear;
+
+impl ScoringRule for Linear {
+    fn points(&self, matches: usize) -> usize {
+        matches
+    }
+}
+
+/// 1 point for the first match, 2 for the second, then the sum of the two previous scores, up to
+/// `usize::MAX`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Fibonacci;
+
+impl ScoringRule for Fibonacci {
+    fn points(&self, matches: usize) -> usize {
+        let (mut previous, mut points): (usize, usize) = (1, usize::from(matches > 0));
+        for _ in 1..matches {
+            (previous, points) = (points, previous.saturating_add(points));
+        }
+        points
+    }
+}
+
+/// Scores as `scoring` does, but wins copies of at most `window` cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cascade<R> {
+    pub scoring: R,
+    pub window: usize,
+}
+
+impl<R: ScoringRule> ScoringRule for Cascade<R> {
+    fn points(&self, matches: usize) -> usize {
+        self.scoring.points(matches)
+    }
+
+    fn copies_won(&self, matches: usize) -> usize {
+        self.scoring.copies_won(matches).min(self.window)
+    }
+}
+
+/// Built-in scorings, as chosen from the command line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Scoring {
+    #[default]
+    Doubling,
+    Linear,
+    Fibonacci,
+}
+
+impl ScoringRule for Scoring {
+    fn points(&self, matches: usize) -> usize {
+        match self {
+            Scoring::Doubling => Doubling.points(matches),
+            Scoring::Linear => Linear.points(matches),
+            Scoring::Fibonacci => Fibonacci.points(matches),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules() {
+        let points =
+            |rule: &dyn ScoringRule| -> Vec<usize> { (0..6).map(|m| rule.points(m)).collect() };
+        assert_eq!(points(&Doubling), vec![0, 1, 2, 4, 8, 16]);
+        assert_eq!(points(&Linear), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(points(&Fibonacci), vec![0, 1, 2, 3, 5, 8]);
+
+        let capped = Cascade {
+            scoring: Scoring::Linear,
+            window: 2,
+        };
+        assert_eq!(capped.points(4), 4);
+        assert_eq!((capped.copies_won(1), capped.copies_won(4)), (1, 2));
+        assert_eq!(Fibonacci.copies_won(4), 4);
+
+        assert_eq!(Doubling.points(64), 1 << 63);
+        assert_eq!(Doubling.points(65), usize::MAX);
+        assert_eq!(Fibonacci.points(92), 12200160415121876738);
+        assert_eq!(Fibonacci.points(200), usize::MAX);
+    }
+}
@@ -0,0 +1,275 @@
+use std::ops::Range;
+
+use aoc_core::DayError;
+use aoc_parse::{header, list, unsigned};
+use combine::{
+    error::StreamError,
+    many1,
+    parser::char::{char, spaces},
+    parser::repeat::skip_until,
+    sep_by,
+    stream::StreamErrorFor,
+    ParseError, Parser, Stream,
+};
+
+use crate::IntervalMap;
+
+/// `dest source length` line of a map.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct AlmanachRange {
+    pub source: usize,
+    pub dest: usize,
+    pub length: usize,
+}
+
+impl AlmanachRange {
+    pub fn source_range(&self) -> Range<usize> {
+        self.source..self.source.saturating_add(self.length)
+    }
+
+    pub fn dest_range(&self) -> Range<usize> {
+        self.dest..self.dest.saturating_add(self.length)
+    }
+
+    /// Source mapped to `value` by this range alone.
+    pub fn unmap(&self, value: usize) -> Option<usize> {
+        self.dest_range()
+            .contains(&value)
+            .then(|| self.source + (value - self.dest))
+    }
+}
+
+/// One map of the almanac; values outside its ranges map to themselves.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct AlmanachMapping(pub Vec<AlmanachRange>);
+
+impl AlmanachMapping {
+    /// Maps `value` through the first range holding it.
+    pub fn map(&self, value: usize) -> usize {
+        self.0
+            .iter()
+            .find(|range| range.source_range().contains(&value))
+            .map_or(value, |range| range.dest + (value - range.source))
+    }
+
+    /// Values mapped to `value`, in order: sources of the ranges holding it, unless an earlier
+    /// range takes them elsewhere, and `value` itself when no range takes it.
+    pub fn unmap(&self, value: usize) -> Vec<usize> {
+        let mut sources: Vec<usize> = self
+            .0
+            .iter()
+            .filter_map(|range| range.unmap(value))
+            .collect();
+        sources.push(value);
+        sources.retain(|&source| self.map(source) == value);
+        sources.sort_unstable();
+        sources.dedup();
+        sources
+    }
+}
+
+/// Seeds of one range lying in a single piece of the composed map, with their locations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    pub seeds: Range<usize>,
+    pub locations: Range<usize>,
+}
+
+/// Seeds and the maps taking them to a location, in order.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub mappings: Vec<AlmanachMapping>,
+}
+
+impl Almanac {
+    pub fn parse(input: &str) -> Result<Self, DayError> {
+        aoc_parse::parse((seeds(), almanach()), input)
+            .map(|(seeds, mappings)| Almanac { seeds, mappings })
+            .map_err(DayError::from)
+    }
+
+    /// Seeds read as `start length` pairs, as in part 2.
+    pub fn seed_ranges(&self) -> Result<Vec<Range<usize>>, DayError> {
+        if self.seeds.len() % 2 == 1 {
+            return Err(DayError::invalid(
+                "seeds do not come in `start length` pairs",
+            ));
+        }
+        self.seeds
+            .chunks(2)
+            .map(|pair| match pair[0].checked_add(pair[1]) {
+                Some(end) => Ok(pair[0]..end),
+                None => Err(DayError::invalid(format!(
+                    "seed range {} {} overflows",
+                    pair[0], pair[1]
+                ))),
+            })
+            .collect()
+    }
+
+    /// Every map composed into one, from seed to location.
+    pub fn locations(&self) -> IntervalMap {
+        self.mappings
+            .iter()
+            .fold(IntervalMap::identity(), |map, mapping| {
+                map.then(&IntervalMap::from(mapping))
+            })
+    }
+
+    /// Seed numbers, in the almanac's seeds or not, landing at `location`.
+    pub fn seeds_at(&self, location: usize) -> Vec<usize> {
+        self.locations().sources(location)
+    }
+
+    /// Seeds of the seed ranges mapped to the lowest locations, `None` without seeds.
+    pub fn lowest_bucket(&self) -> Result<Option<Bucket>, DayError> {
+        let locations = self.locations();
+        Ok(self
+            .seed_ranges()?
+            .into_iter()
+            .flat_map(|seeds| locations.parts(seeds))
+            .map(|(seeds, locations)| Bucket { seeds, locations })
+            .min_by_key(|bucket| bucket.locations.start))
+    }
+}
+
+fn almanach_range<Input>() -> impl Parser<Input, Output = AlmanachRange>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    (
+        unsigned().skip(spaces()),
+        unsigned().skip(spaces()),
+        unsigned().skip(spaces()),
+    )
+        .and_then(|(dest, source, length): (usize, usize, usize)| {
+            if source.checked_add(length).is_none() || dest.checked_add(length).is_none() {
+                return Err(StreamErrorFor::<Input>::message_format(format_args!(
+                    "range `{dest} {source} {length}` overflows"
+                )));
+            }
+            Ok(AlmanachRange {
+                source,
+                dest,
+                length,
+            })
+        })
+}
+
+fn almanach_mapping<Input>() -> impl Parser<Input, Output = AlmanachMapping>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    (
+        skip_until(char(':')).skip(char(':')).skip(spaces()),
+        sep_by(almanach_range(), spaces()),
+    )
+        .map(|((), ranges): ((), Vec<AlmanachRange>)| AlmanachMapping(ranges))
+}
+
+fn almanach<Input>() -> impl Parser<Input, Output = Vec<AlmanachMapping>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    many1(almanach_mapping())
+}
+
+fn seeds<Input>() -> impl Parser<Input, Output = Vec<usize>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    header("seeds", list(unsigned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use combine::EasyParser;
+
+    #[test]
+    fn test_seeds() {
+        let input = "seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 52";
+        let output = seeds().easy_parse(input).unwrap();
+        assert_eq!(output.0, vec![79, 14, 55, 13]);
+    }
+
+    #[test]
+    fn test_almanach() {
+        let input = "seed-to-soil map:
+        30 52 39
+        40 90 2
+
+        seed-to-soil map:
+        22 52 8
+        12 20 3";
+        let expected = AlmanachRange {
+            source: 52,
+            dest: 22,
+            length: 8,
+        };
+        let output = almanach().easy_parse(input).unwrap().0;
+        assert_eq!(output[1].0[0], expected);
+        assert_eq!(
+            (output[0].map(53), output[0].map(91), output[0].map(7)),
+            (31, 41, 7)
+        );
+    }
+
+    #[test]
+    fn test_almanac() {
+        let almanac = Almanac::parse(include_str!("./test.txt")).unwrap();
+        assert_eq!(almanac.mappings.len(), 7);
+        assert_eq!(almanac.seed_ranges().unwrap(), vec![79..93, 55..68]);
+        let locations = almanac.locations();
+        let each: Vec<usize> = almanac
+            .seeds
+            .iter()
+            .map(|&seed| almanac.mappings.iter().fold(seed, |value, m| m.map(value)))
+            .collect();
+        let composed: Vec<usize> = almanac
+            .seeds
+            .iter()
+            .map(|&seed| locations.get(seed))
+            .collect();
+        assert_eq!(composed, each);
+        assert_eq!(each, vec![82, 43, 86, 35]);
+
+        assert_eq!(almanac.seeds_at(35), vec![13]);
+        assert_eq!(
+            almanac.lowest_bucket().unwrap(),
+            Some(Bucket {
+                seeds: 82..92,
+                locations: 46..56
+            })
+        );
+
+        let odd = Almanac {
+            seeds: vec![1, 2, 3],
+            ..almanac
+        };
+        assert!(odd.seed_ranges().is_err());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = usize::MAX;
+        let range = format!("seeds: 1 2\n\nseed-to-soil map:\n0 {max} 2\n");
+        assert!(Almanac::parse(&range).is_err());
+        let range = format!("seeds: 1 2\n\nseed-to-soil map:\n{max} 0 2\n");
+        assert!(Almanac::parse(&range).is_err());
+
+        let seeds = format!("seeds: {max} 2\n\nseed-to-soil map:\n0 5 2\n");
+        let almanac = Almanac::parse(&seeds).unwrap();
+        assert!(almanac.seed_ranges().is_err());
+        assert!(almanac.lowest_bucket().is_err());
+        assert!(almanac.seeds_at(max).is_empty());
+    }
+}
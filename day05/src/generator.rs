@@ -0,0 +1,68 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "seeds",
+            default: 10,
+            range: 1..=10_000_000,
+            help: "number of seed ranges",
+        },
+        Param {
+            name: "ranges",
+            default: 30,
+            range: 1..=10_000_000,
+            help: "maximum number of ranges in each map",
+        },
+    ],
+    generate,
+};
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Values stay below 2^32 like in the official input.
+const LIMIT: usize = 1 << 32;
+
+/// `destination source length` lines whose source ranges never overlap: they are every other
+/// interval between sorted random cuts.
+fn map(rng: &mut Rng, ranges: usize) -> Vec<String> {
+    let mut cuts: Vec<usize> = (0..2 * ranges).map(|_| rng.below(LIMIT)).collect();
+    cuts.sort_unstable();
+    let mut lines: Vec<String> = cuts
+        .chunks(2)
+        .filter(|pair| pair[0] < pair[1])
+        .map(|pair| {
+            let length = pair[1] - pair[0];
+            let destination = rng.below(LIMIT - length);
+            format!("{destination} {} {length}", pair[0])
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines
+}
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let seeds: Vec<String> = (0..sizes.get("seeds"))
+        .map(|_| {
+            let length = rng.range(1..=1 << 28);
+            format!("{} {length}", rng.below(LIMIT - length))
+        })
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+    for name in MAPS {
+        let mut lines = map(rng, sizes.get("ranges"));
+        if lines.is_empty() {
+            lines.push(String::from("0 0 1"));
+        }
+        input.push_str(&format!("\n{name} map:\n{}\n", lines.join("\n")));
+    }
+    input
+}
@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::AlmanachMapping;
+
+/// Values from `start` up to the start of the next piece, mapped to `dest` onwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Piece {
+    pub start: usize,
+    pub dest: usize,
+}
+
+/// Function over every `usize` shifting each interval between cuts by its own offset, so that a
+/// value is mapped by a binary search and a range by one plus a step per cut it crosses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalMap {
+    /// Sorted by start, the first one at 0.
+    pieces: Vec<Piece>,
+}
+
+impl IntervalMap {
+    pub fn identity() -> Self {
+        IntervalMap {
+            pieces: vec![Piece { start: 0, dest: 0 }],
+        }
+    }
+
+    /// Map of sorted pieces, merging those continuing the previous one.
+    fn new(pieces: impl IntoIterator<Item = Piece>) -> Self {
+        let mut merged: Vec<Piece> = Vec::new();
+        for piece in pieces {
+            match merged.last() {
+                Some(last)
+                    if last.dest.checked_add(piece.start - last.start) == Some(piece.dest) => {}
+                _ => merged.push(piece),
+            }
+        }
+        IntervalMap { pieces: merged }
+    }
+
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    /// Values of the piece `i`.
+    fn domain(&self, i: usize) -> Range<usize> {
+        let end = self.pieces.get(i + 1).map_or(usize::MAX, |next| next.start);
+        self.pieces[i].start..end
+    }
+
+    pub fn get(&self, value: usize) -> usize {
+        let piece = self.pieces[self.pieces.partition_point(|p| p.start <= value) - 1];
+        piece.dest + (value - piece.start)
+    }
+
+    /// Parts of `range` lying in a single piece, in order, each with its image.
+    pub fn parts(
+        &self,
+        range: Range<usize>,
+    ) -> impl Iterator<Item = (Range<usize>, Range<usize>)> + '_ {
+        let Range { start, end } = range;
+        let first = self.pieces.partition_point(|p| p.start <= start) - 1;
+        let pieces = if start < end {
+            self.pieces.len()
+        } else {
+            first
+        };
+        (first..pieces)
+            .map(|i| (i, self.domain(i)))
+            .take_while(move |(_, domain)| domain.start < end)
+            .map(move |(i, domain)| {
+                let part = start.max(domain.start)..end.min(domain.end);
+                let dest = self.pieces[i].dest + (part.start - domain.start);
+                let image = dest..dest.saturating_add(part.len());
+                (part, image)
+            })
+    }
+
+    /// Images of `range`, one per piece it crosses, in the order of the values mapped.
+    pub fn image(&self, range: Range<usize>) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.parts(range).map(|(_, image)| image)
+    }
+
+    /// Every value mapped into `range`, as sorted disjoint ranges. Pieces may overlap once
+    /// mapped, so all of them are looked at.
+    pub fn preimage(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let mut preimage: Vec<Range<usize>> = Vec::new();
+        for (i, piece) in self.pieces.iter().enumerate() {
+            let domain = self.domain(i);
+            let image = piece.dest..piece.dest.saturating_add(domain.len());
+            let (start, end) = (range.start.max(image.start), range.end.min(image.end));
+            if start >= end {
+                continue;
+            }
+            let part = domain.start + (start - piece.dest)..domain.start + (end - piece.dest);
+            match preimage.last_mut() {
+                Some(last) if last.end == part.start => last.end = part.end,
+                _ => preimage.push(part),
+            }
+        }
+        preimage
+    }
+
+    /// Values mapped to `value`, in order. Images end before `usize::MAX`, so nothing maps there.
+    pub fn sources(&self, value: usize) -> Vec<usize> {
+        self.preimage(value..value.saturating_add(1))
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// This map followed by `next`.
+    pub fn then(&self, next: &IntervalMap) -> IntervalMap {
+        IntervalMap::new((0..self.pieces.len()).flat_map(|i| {
+            let piece = self.pieces[i];
+            let image = piece.dest..piece.dest.saturating_add(self.domain(i).len());
+            next.parts(image).map(move |(part, image)| Piece {
+                start: piece.start + (part.start - piece.dest),
+                dest: image.start,
+            })
+        }))
+    }
+}
+
+/// Ranges listed first win where source ranges overlap, as in [`AlmanachMapping::map`].
+impl From<&AlmanachMapping> for IntervalMap {
+    fn from(mapping: &AlmanachMapping) -> Self {
+        // disjoint source ranges as start -> (end, dest), their ends thus sorted too
+        let mut covered: BTreeMap<usize, (usize, usize)> = BTreeMap::new();
+        for range in &mapping.0 {
+            let source = range.source..range.source.saturating_add(range.length);
+            let mut overlapping: Vec<(usize, usize)> = covered
+                .range(..source.end)
+                .rev()
+                .map(|(&start, &(end, _))| (start, end))
+                .take_while(|&(_, end)| end > source.start)
+                .collect();
+            overlapping.reverse();
+            let mut cursor = source.start;
+            let gaps = overlapping.into_iter().chain([(source.end, source.end)]);
+            for (start, end) in gaps {
+                if cursor < start {
+                    covered.insert(cursor, (start, range.dest + (cursor - range.source)));
+                }
+                cursor = cursor.max(end);
+            }
+        }
+
+        let mut pieces = Vec::new();
+        let mut cursor = 0;
+        for (start, (end, dest)) in covered {
+            if cursor < start {
+                pieces.push(Piece {
+                    start: cursor,
+                    dest: cursor,
+                });
+            }
+            pieces.push(Piece { start, dest });
+            cursor = end;
+        }
+        if cursor < usize::MAX || pieces.is_empty() {
+            pieces.push(Piece {
+                start: cursor,
+                dest: cursor,
+            });
+        }
+        IntervalMap::new(pieces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AlmanachRange;
+
+    fn map(ranges: &[(usize, usize, usize)]) -> IntervalMap {
+        let ranges = ranges
+            .iter()
+            .map(|&(dest, source, length)| AlmanachRange {
+                source,
+                dest,
+                length,
+            })
+            .collect();
+        IntervalMap::from(&AlmanachMapping(ranges))
+    }
+
+    #[test]
+    fn test_image() {
+        let image = |ranges: &[(usize, usize, usize)]| -> Vec<Range<usize>> {
+            map(ranges).image(5..10).collect()
+        };
+        assert_eq!(image(&[(2, 11, 2)]), vec![5..10]);
+        assert_eq!(image(&[(2, 3, 2)]), vec![5..10]);
+        assert_eq!(image(&[(20, 3, 4)]), vec![22..24, 7..10]);
+        assert_eq!(image(&[(20, 8, 4)]), vec![5..8, 20..22]);
+        assert_eq!(image(&[(20, 0, 15)]), vec![25..30]);
+        assert_eq!(image(&[(20, 6, 2)]), vec![5..6, 20..22, 8..10]);
+        assert_eq!(map(&[]).image(5..5).count(), 0);
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let mapping = map(&[(100, 10, 10), (200, 5, 20), (0, 0, 0)]);
+        assert_eq!(mapping.get(12), 102);
+        assert_eq!(mapping.get(7), 202);
+        assert_eq!(mapping.get(22), 217);
+        assert_eq!(mapping.get(25), 25);
+        assert_eq!(mapping.pieces().len(), 5);
+    }
+
+    #[test]
+    fn test_then() {
+        let first = map(&[(50, 98, 2), (52, 50, 48)]);
+        let second = map(&[(0, 15, 37), (37, 52, 2), (39, 0, 15)]);
+        let both = first.then(&second);
+        for value in [0, 14, 15, 49, 50, 51, 79, 97, 98, 99, 100, 1000] {
+            assert_eq!(both.get(value), second.get(first.get(value)), "{value}");
+        }
+        assert_eq!(IntervalMap::identity().then(&first), first);
+        assert_eq!(both.get(usize::MAX - 1), usize::MAX - 1);
+    }
+
+    #[test]
+    fn test_preimage() {
+        let mapping = map(&[(100, 10, 10), (200, 5, 20), (10, 100, 5)]);
+        assert_eq!(mapping.sources(102), vec![12]);
+        assert_eq!(mapping.sources(12), vec![102]);
+        assert_eq!(mapping.sources(7), vec![]);
+        assert_eq!(mapping.sources(202), vec![7, 202]);
+        assert_eq!(mapping.preimage(0..110), vec![0..5, 10..20, 25..110]);
+        assert_eq!(IntervalMap::identity().preimage(3..7), vec![3..7]);
+    }
+}
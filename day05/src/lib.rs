@@ -0,0 +1,158 @@
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::{Answer, DayError, Part};
+
+pub mod almanac;
+pub mod generator;
+pub mod interval;
+
+pub use almanac::{Almanac, AlmanachMapping, AlmanachRange, Bucket};
+pub use interval::{IntervalMap, Piece};
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+    /// Print the seeds landing at this location instead of the answers
+    #[clap(long)]
+    pub seeds_at: Option<usize>,
+    /// Print the seeds of the ranges mapped to the lowest locations instead of the answers
+    #[clap(long)]
+    pub lowest_bucket: bool,
+}
+
+/// Lowest location of the seeds.
+fn lowest(almanac: &Almanac) -> Result<usize, DayError> {
+    let locations = almanac.locations();
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| locations.get(seed))
+        .min()
+        .ok_or_else(|| DayError::invalid("no seeds"))
+}
+
+/// Lowest location of the seed ranges.
+fn lowest_of_ranges(almanac: &Almanac) -> Result<usize, DayError> {
+    almanac
+        .lowest_bucket()?
+        .map(|bucket| bucket.locations.start)
+        .ok_or_else(|| DayError::invalid("no seeds"))
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    lowest(&Almanac::parse(input)?).map(Answer::from)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    lowest_of_ranges(&Almanac::parse(input)?).map(Answer::from)
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    let solve = if part == 1 { lowest } else { lowest_of_ranges };
+    bench::measure(input, runs, Almanac::parse, |almanac| {
+        solve(&almanac).map(Answer::from)
+    })
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, Almanac::parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parts() {
+        let input = include_str!("./test.txt");
+        assert_eq!(part1(input), Ok(Answer::Unsigned(35)));
+        assert_eq!(part2(input), Ok(Answer::Unsigned(46)));
+    }
+
+    const CATEGORIES: [&str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+
+    type RawAlmanac = (Vec<(usize, usize)>, Vec<Vec<(usize, usize, usize)>>);
+
+    /// Seed ranges as `(start, length)` and maps whose ranges are `(dest, gap, length)`, the gap
+    /// separating a source range from the previous one so that they never overlap.
+    fn almanac() -> impl Strategy<Value = RawAlmanac> {
+        (
+            vec((0usize..100, 1usize..20), 1..4),
+            vec(vec((0usize..150, 0usize..10, 1usize..30), 1..5), 1..5),
+        )
+    }
+
+    fn text(seeds: &[usize], maps: &[Vec<(usize, usize, usize)>]) -> String {
+        let seeds: Vec<String> = seeds.iter().map(usize::to_string).collect();
+        let mut text = format!("seeds: {}\n", seeds.join(" "));
+        for (i, ranges) in maps.iter().enumerate() {
+            text.push_str(&format!(
+                "\n{}-to-{} map:\n",
+                CATEGORIES[i],
+                CATEGORIES[i + 1]
+            ));
+            let mut source = 0;
+            for &(dest, gap, length) in ranges {
+                source += gap;
+                text.push_str(&format!("{dest} {source} {length}\n"));
+                source += length;
+            }
+        }
+        text
+    }
+
+    proptest! {
+        #[test]
+        fn ranges_match_single_seeds((seeds, maps) in almanac()) {
+            let pairs: Vec<usize> = seeds.iter().flat_map(|&(start, length)| [start, length]).collect();
+            let each: Vec<usize> = seeds
+                .iter()
+                .flat_map(|&(start, length)| start..start + length)
+                .collect();
+            prop_assert_eq!(
+                part2(&text(&pairs, &maps)).unwrap(),
+                part1(&text(&each, &maps)).unwrap()
+            );
+        }
+
+        #[test]
+        fn composed_matches_layers((seeds, maps) in almanac(), values in vec(0usize..300, 1..20)) {
+            let pairs: Vec<usize> = seeds.iter().flat_map(|&(start, length)| [start, length]).collect();
+            let almanac = Almanac::parse(&text(&pairs, &maps)).unwrap();
+            let locations = almanac.locations();
+            for value in values {
+                let layered = almanac.mappings.iter().fold(value, |value, m| m.map(value));
+                prop_assert_eq!(locations.get(value), layered);
+            }
+        }
+
+        #[test]
+        fn inverse_matches_layers((_, maps) in almanac(), location in 0usize..300) {
+            let almanac = Almanac::parse(&text(&[0], &maps)).unwrap();
+            let mut layered = vec![location];
+            for mapping in almanac.mappings.iter().rev() {
+                layered = layered.iter().flat_map(|&value| mapping.unmap(value)).collect();
+            }
+            layered.sort_unstable();
+            prop_assert_eq!(almanac.seeds_at(location), layered);
+        }
+    }
+}
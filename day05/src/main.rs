@@ -0,0 +1,45 @@
+use anyhow::Result;
+use aoc_core::input;
+use clap::Parser;
+use day05::Almanac;
+use std::process::ExitCode;
+
+fn main() -> Result<ExitCode> {
+    let opts = day05::Opts::parse();
+    let input = input::read(opts.input.as_ref(), day05::INPUT)?;
+    if opts.seeds_at.is_some() || opts.lowest_bucket {
+        let almanac = match Almanac::parse(&input) {
+            Ok(almanac) => almanac,
+            Err(err) => {
+                eprintln!("{}", err.report(&input));
+                return Ok(ExitCode::FAILURE);
+            }
+        };
+        if let Some(location) = opts.seeds_at {
+            let seeds: Vec<String> = almanac
+                .seeds_at(location)
+                .iter()
+                .map(usize::to_string)
+                .collect();
+            println!("location {location}: seeds {}", seeds.join(", "));
+        }
+        if opts.lowest_bucket {
+            match almanac.lowest_bucket() {
+                Ok(Some(bucket)) => println!(
+                    "seeds {}..{} land at locations {}..{}",
+                    bucket.seeds.start,
+                    bucket.seeds.end,
+                    bucket.locations.start,
+                    bucket.locations.end
+                ),
+                Ok(None) => println!("no seeds"),
+                Err(err) => {
+                    eprintln!("{}", err.report(&input));
+                    return Ok(ExitCode::FAILURE);
+                }
+            }
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+    Ok(aoc_core::run(opts.part, &input, day05::part1, day05::part2))
+}
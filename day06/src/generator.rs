@@ -0,0 +1,49 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+
+/// Part 2 reads the sheet as one race, so both parameters are capped for its numbers to fit in
+/// 64 bits.
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "races",
+            default: 4,
+            range: 1..=4,
+            help: "number of races",
+        },
+        Param {
+            name: "time",
+            default: 99,
+            range: 7..=99,
+            help: "maximum duration of a race",
+        },
+    ],
+    generate,
+};
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let (times, records): (Vec<usize>, Vec<usize>) = (0..sizes.get("races"))
+        .map(|_| {
+            let time = rng.range(7..=sizes.get("time"));
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.below(best))
+        })
+        .unzip();
+    let row = |values: Vec<usize>| values.iter().map(|v| format!("{v:>6}")).collect::<String>();
+    format!("Time:    {}\nDistance:{}\n", row(times), row(records))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let input = GENERATOR.generate(seed, &[]).unwrap();
+            // every race can be won
+            assert!(part1(&input).is_ok_and(|answer| answer.to_string() != "0"));
+            assert!(part2(&input).is_ok());
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::{Answer, DayError, Part};
+use aoc_parse::{header, list, unsigned};
+use combine::{
+    error::StreamError,
+    many1,
+    parser::char::{digit, newline},
+    stream::StreamErrorFor,
+    ParseError, Parser, Stream,
+};
+
+pub mod generator;
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+}
+
+pub fn count_ways_win(time: usize, distance: usize) -> usize {
+    // no press wins unless the search below finds one
+    let mut left_bound: usize = time + 1;
+    let mut right_bound: usize = 0;
+
+    //find left border
+    for press_time in 0..time + 1 {
+        let attempt_distance = (time - press_time) * press_time;
+        if attempt_distance > distance {
+            left_bound = press_time;
+            break;
+        }
+    }
+
+    //find right border
+    for press_time in (0..time + 1).rev() {
+        let attempt_distance = (time - press_time) * press_time;
+        if attempt_distance > distance {
+            right_bound = press_time;
+            break;
+        }
+    }
+
+    match left_bound <= right_bound {
+        true => 1 + right_bound - left_bound,
+        false => 0,
+    }
+}
+
+/// The `Time:` and `Distance:` lines, each read with `values`.
+fn sheet<Input, P>(values: impl Fn() -> P) -> impl Parser<Input, Output = (P::Output, P::Output)>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    P: Parser<Input>,
+{
+    (
+        header("Time", values()).skip(newline()),
+        header("Distance", values()),
+    )
+}
+
+/// A single number written with spaces between its digits.
+fn kerned<Input>() -> impl Parser<Input, Output = usize>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    list(many1::<String, _, _>(digit())).and_then(|digits: Vec<String>| {
+        digits
+            .concat()
+            .parse()
+            .map_err(StreamErrorFor::<Input>::other)
+    })
+}
+
+fn races(input: &str) -> Result<(Vec<usize>, Vec<usize>), DayError> {
+    aoc_parse::parse(sheet(|| list(unsigned())), input).map_err(DayError::from)
+}
+
+fn race(input: &str) -> Result<(usize, usize), DayError> {
+    // the spaces between the numbers are ignored
+    aoc_parse::parse(sheet(kerned), input).map_err(DayError::from)
+}
+
+fn margin((times, distances): (Vec<usize>, Vec<usize>)) -> Result<Answer, DayError> {
+    if times.len() != distances.len() {
+        return Err(DayError::invalid("not as many distances as times"));
+    }
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(t, d)| count_ways_win(t, d))
+        .product::<usize>()
+        .into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    races(input).and_then(margin)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    let (time, distance) = race(input)?;
+    Ok(count_ways_win(time, distance).into())
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    match part {
+        1 => bench::measure(input, runs, races, margin),
+        _ => bench::measure(input, runs, race, |(time, distance)| {
+            Ok(count_ways_win(time, distance).into())
+        }),
+    }
+}
+
+pub fn bench_parse(part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    match part {
+        1 => bench::measure_parse(input, runs, races),
+        _ => bench::measure_parse(input, runs, race),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+        assert_eq!(part1(input), Ok(Answer::Unsigned(288)));
+        assert_eq!(part2(input), Ok(Answer::Unsigned(71503)));
+    }
+
+    #[test]
+    fn test_single_way() {
+        // only holding for 4ms beats 15mm
+        assert_eq!(count_ways_win(8, 15), 1);
+        assert_eq!(count_ways_win(8, 16), 0);
+        assert_eq!(count_ways_win(7, 9), 4);
+    }
+}
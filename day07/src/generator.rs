@@ -0,0 +1,25 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+
+pub const GENERATOR: Generator = Generator {
+    params: &[Param {
+        name: "hands",
+        default: 1000,
+        range: 1..=100_000_000,
+        help: "number of hands",
+    }],
+    generate,
+};
+
+const CARDS: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    (0..sizes.get("hands"))
+        .map(|_| {
+            let hand: String = (0..5).map(|_| *rng.choose(&CARDS)).collect();
+            format!("{hand} {}", rng.range(1..=1000))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
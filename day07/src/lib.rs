@@ -0,0 +1,283 @@
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::stream::LineSolver;
+use aoc_core::{Answer, DayError, Part};
+use aoc_parse::{lines, unsigned};
+use combine::{
+    parser::char::space, parser::repeat::count_min_max, parser::token::one_of, ParseError, Parser,
+    Stream,
+};
+
+pub mod generator;
+pub mod part1;
+pub mod part2;
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+    /// Read the input line by line instead of loading it whole
+    #[clap(long)]
+    pub stream: bool,
+}
+
+/// Hand types from the weakest to the strongest.
+#[derive(Hash, Eq, PartialEq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub(crate) enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// Type of a hand holding `counts` of each of its cards.
+    pub(crate) fn of(counts: HashMap<char, u8>) -> HandType {
+        let mut counts = counts.into_values().sorted().collect::<Vec<u8>>();
+        match counts.pop() {
+            Some(5) => HandType::FiveOfAKind,
+            Some(4) => HandType::FourOfAKind,
+            Some(3) => match counts.pop() {
+                Some(2) => HandType::FullHouse,
+                _ => HandType::ThreeOfAKind,
+            },
+            Some(2) => match counts.pop() {
+                Some(2) => HandType::TwoPair,
+                _ => HandType::OnePair,
+            },
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+/// How one part ranks the cards and types the hands.
+pub(crate) struct Rules {
+    /// Labels from the weakest card to the strongest.
+    pub order: &'static str,
+    pub hand_type: fn(HashMap<char, u8>) -> HandType,
+}
+
+#[derive(PartialEq, Debug, Eq, Hash, Clone)]
+pub(crate) struct Hand {
+    /// Strength of each card, in the order they were dealt.
+    cards: Vec<usize>,
+    hand_type: HandType,
+    bid: usize,
+}
+
+impl Hand {
+    fn new(hand: &str, bid: usize, rules: &Rules) -> Self {
+        let mut counts: HashMap<char, u8> = HashMap::new();
+        for c in hand.chars() {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+        let cards = hand
+            .chars()
+            .map(|c| {
+                rules
+                    .order
+                    .find(c)
+                    .expect("cards are checked by the parser")
+            })
+            .collect();
+        Self {
+            cards,
+            hand_type: (rules.hand_type)(counts),
+            bid,
+        }
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // same cards compare equal whatever the bids
+        (self.hand_type, &self.cards).cmp(&(other.hand_type, &other.cards))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Labels of the cards a hand is made of, five of them.
+const CARDS: &str = "AKQJT98765432";
+
+fn hand<'a, Input>(rules: &'a Rules) -> impl Parser<Input, Output = Hand> + 'a
+where
+    Input: Stream<Token = char> + 'a,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    let cards = count_min_max(5, 5, one_of(CARDS.chars()));
+    (cards.skip(space()).and(unsigned()))
+        .map(move |(hand, bid): (String, usize)| Hand::new(&hand, bid, rules))
+}
+
+fn game<'a, Input>(rules: &'a Rules) -> impl Parser<Input, Output = Vec<Hand>> + 'a
+where
+    Input: Stream<Token = char> + 'a,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    lines(hand(rules))
+}
+
+fn parse(input: &str, rules: &Rules) -> Result<Vec<Hand>, DayError> {
+    aoc_parse::parse(game(rules), input).map_err(DayError::from)
+}
+
+/// Total winnings, the hands being ranked from the weakest.
+fn solve(hands: Vec<Hand>) -> usize {
+    hands
+        .into_iter()
+        .sorted()
+        .enumerate()
+        .map(|(i, hand)| (i + 1) * hand.bid)
+        .sum()
+}
+
+/// Parses one line, the bid being split from the hand so that equal hands compare equal.
+fn split(line: &str, rules: &Rules) -> Result<(Hand, usize), DayError> {
+    let hand = aoc_parse::parse(hand(rules), line)?;
+    let bid = hand.bid;
+    Ok((Hand { bid: 0, ..hand }, bid))
+}
+
+fn rules(part: u8) -> &'static Rules {
+    match part {
+        1 => &part1::RULES,
+        _ => &part2::RULES,
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    parse(input, rules(1)).map(|hands| solve(hands).into())
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    parse(input, rules(2)).map(|hands| solve(hands).into())
+}
+
+/// Bids of one distinct hand, `weighted` summing each bid times the number of equal hands
+/// read before it.
+#[derive(Debug, Default)]
+struct Bids {
+    count: usize,
+    sum: usize,
+    weighted: usize,
+}
+
+/// Bids tallied per distinct hand. There are at most 13^5 different hands, so memory stays
+/// bounded however long the input is; equal hands are ranked in input order.
+struct Winnings {
+    rules: &'static Rules,
+    hands: BTreeMap<Hand, Bids>,
+}
+
+impl LineSolver for Winnings {
+    fn feed(&mut self, line: &str) -> Result<(), DayError> {
+        let (hand, bid) = split(line, self.rules)?;
+        let bids = self.hands.entry(hand).or_default();
+        bids.weighted += bids.count * bid;
+        bids.count += 1;
+        bids.sum += bid;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<Answer, DayError> {
+        let mut rank = 1;
+        let mut total = 0;
+        for bids in self.hands.values() {
+            total += rank * bids.sum + bids.weighted;
+            rank += bids.count;
+        }
+        Ok(total.into())
+    }
+}
+
+pub fn stream(part: u8) -> Box<dyn LineSolver> {
+    Box::new(Winnings {
+        rules: rules(part),
+        hands: BTreeMap::new(),
+    })
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    let rules = rules(part);
+    bench::measure(
+        input,
+        runs,
+        |input| parse(input, rules),
+        |hands| Ok(solve(hands).into()),
+    )
+}
+
+pub fn bench_parse(part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    let rules = rules(part);
+    bench::measure_parse(input, runs, |input| parse(input, rules))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use combine::EasyParser;
+
+    #[test]
+    fn test_parse() {
+        let input = "32T3K 765
+T55J5 684";
+        let (game, rest) = game(&part1::RULES).easy_parse(input).unwrap();
+        assert!(rest.is_empty());
+        let types: Vec<(HandType, usize)> = game.iter().map(|h| (h.hand_type, h.bid)).collect();
+        assert_eq!(
+            types,
+            vec![(HandType::OnePair, 765), (HandType::ThreeOfAKind, 684)]
+        );
+    }
+
+    #[test]
+    fn test_trailing_newline() {
+        let input = format!("{INPUT}\n");
+        assert_eq!(part1(&input), part1(INPUT));
+        assert_eq!(part2(&input), part2(INPUT));
+    }
+
+    #[test]
+    fn test_bad_hands() {
+        for input in ["ZZZZZ 1", "AAAAAA 1", "AAAA 1"] {
+            assert!(part1(input).is_err() && part2(input).is_err(), "{input}");
+        }
+        assert_eq!(part2("JJJJJ 3"), Ok(Answer::Unsigned(3)));
+    }
+
+    #[test]
+    fn test_stream() {
+        let answers = aoc_core::stream::solve(&[1, 2], INPUT.as_bytes(), stream).unwrap();
+        assert_eq!(answers, vec![part1(INPUT).unwrap(), part2(INPUT).unwrap()]);
+
+        // equal hands are ranked in input order, as when sorting the whole input
+        let input = "32T3K 5\nKK677 1\n32T3K 7";
+        let answers = aoc_core::stream::solve(&[1], input.as_bytes(), stream).unwrap();
+        assert_eq!(answers, vec![part1(input).unwrap()]);
+    }
+
+    #[test]
+    fn test_stream_trailing_blank_line() {
+        let input = format!("{INPUT}\n\n");
+        let answers = aoc_core::stream::solve(&[1, 2], input.as_bytes(), stream).unwrap();
+        assert_eq!(answers, vec![part1(INPUT).unwrap(), part2(INPUT).unwrap()]);
+    }
+}
@@ -0,0 +1,29 @@
+//! Jacks are plain cards, ranking between queens and tens.
+
+use crate::{HandType, Rules};
+
+pub(crate) const RULES: Rules = Rules {
+    order: "23456789TJQKA",
+    hand_type: HandType::of,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hand;
+
+    #[test]
+    fn test_compare_hands() {
+        let one_pair1 = Hand::new("T9T35", 1, &RULES);
+        let one_pair2 = Hand::new("2AA46", 1, &RULES);
+        let full_house = Hand::new("6AAA6", 1, &RULES);
+        let three_of_a_kind1 = Hand::new("T88K8", 1, &RULES);
+        let three_of_a_kind2 = Hand::new("Q777K", 1, &RULES);
+
+        assert!(one_pair1 > one_pair2);
+        assert!(full_house > one_pair1);
+        assert_eq!(three_of_a_kind1.hand_type, HandType::ThreeOfAKind);
+        assert_eq!(three_of_a_kind2.hand_type, HandType::ThreeOfAKind);
+        assert!(three_of_a_kind2 > three_of_a_kind1);
+    }
+}
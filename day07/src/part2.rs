@@ -0,0 +1,57 @@
+//! Jacks are jokers: the weakest card, but they count as the card the hand holds the most of.
+
+use std::collections::HashMap;
+
+use crate::{HandType, Rules};
+
+pub(crate) const RULES: Rules = Rules {
+    order: "J23456789TQKA",
+    hand_type,
+};
+
+fn hand_type(mut counts: HashMap<char, u8>) -> HandType {
+    if let Some(jokers) = counts.remove(&'J') {
+        match counts.values_mut().max() {
+            Some(most) => *most += jokers,
+            None => {
+                counts.insert('J', jokers);
+            }
+        }
+    }
+    HandType::of(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hand;
+
+    #[test]
+    fn test_compare_hands() {
+        let one_pair1 = Hand::new("Q9Q35", 1, &RULES);
+        let one_pair2 = Hand::new("2AA46", 1, &RULES);
+        let full_house = Hand::new("6AAA6", 1, &RULES);
+        let three_of_a_kind1 = Hand::new("T88K8", 1, &RULES);
+        let three_of_a_kind2 = Hand::new("Q777K", 1, &RULES);
+        let three_of_a_kind_with_joker3 = Hand::new("J772K", 1, &RULES);
+        let five_jokers = Hand::new("JJJJJ", 1, &RULES);
+
+        //hand types
+        assert_eq!(three_of_a_kind1.hand_type, HandType::ThreeOfAKind);
+        assert_eq!(three_of_a_kind2.hand_type, HandType::ThreeOfAKind);
+        assert_eq!(
+            Hand::new("T55J5", 1, &RULES).hand_type,
+            HandType::FourOfAKind
+        );
+
+        //hand strength
+        assert!(one_pair1 > one_pair2);
+        assert!(full_house > one_pair1);
+        assert!(three_of_a_kind_with_joker3 > one_pair1);
+        assert!(three_of_a_kind_with_joker3 > one_pair2);
+        assert!(full_house > three_of_a_kind_with_joker3);
+        assert!(three_of_a_kind2 > three_of_a_kind1);
+        assert!(three_of_a_kind2 > three_of_a_kind_with_joker3);
+        assert!(five_jokers > three_of_a_kind_with_joker3);
+    }
+}
@@ -0,0 +1,120 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "ghosts",
+            default: 6,
+            range: 1..=15,
+            help: "number of starting nodes",
+        },
+        Param {
+            name: "directions",
+            default: 263,
+            range: 1..=100_000,
+            help: "length of the list of directions",
+        },
+    ],
+    generate,
+};
+
+/// The loop of every ghost is the length of the directions times its own prime, so that part 2
+/// can rely on the loops being multiples of each other only through the directions.
+const PRIMES: [usize; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+/// Name made of letters other than `A` and `Z`, at least three of them.
+fn name(mut index: usize, width: usize) -> String {
+    let mut name = vec![];
+    while index > 0 || name.len() < width {
+        name.push(b'B' + (index % 24) as u8);
+        index /= 24;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+/// Nodes of one ghost: its start, the `steps - 1` nodes it walks through and its end. The branch
+/// taken at each step leads to the next node, the other one anywhere in the loop, and the end
+/// branches like the start so that the walk loops.
+fn ghost(
+    rng: &mut Rng,
+    directions: &[bool],
+    steps: usize,
+    ends: (String, String),
+    next_name: &mut usize,
+) -> Vec<(String, [String; 2])> {
+    let mut path = vec![ends.0];
+    for _ in 1..steps {
+        path.push(name(*next_name, 3));
+        *next_name += 1;
+    }
+    let mut nodes = vec![];
+    for (i, node) in path.iter().enumerate() {
+        let next = path.get(i + 1).unwrap_or(&ends.1).clone();
+        let other = rng.choose(&path).clone();
+        let branches = match directions[i % directions.len()] {
+            true => [next, other],
+            false => [other, next],
+        };
+        nodes.push((node.clone(), branches));
+    }
+    let start = nodes[0].1.clone();
+    nodes.push((ends.1, start));
+    nodes
+}
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let directions: Vec<bool> = (0..sizes.get("directions"))
+        .map(|_| rng.chance(50))
+        .collect();
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+
+    let mut nodes = vec![];
+    let mut next_name = 0;
+    for (g, prime) in primes[..sizes.get("ghosts")].iter().enumerate() {
+        let ends = match g {
+            0 => (String::from("AAA"), String::from("ZZZ")),
+            g => (name(g, 2) + "A", name(g, 2) + "Z"),
+        };
+        let steps = directions.len() * prime;
+        nodes.extend(ghost(rng, &directions, steps, ends, &mut next_name));
+    }
+    rng.shuffle(&mut nodes);
+
+    let directions: String = directions
+        .iter()
+        .map(|&left| if left { 'L' } else { 'R' })
+        .collect();
+    let nodes: Vec<String> = nodes
+        .iter()
+        .map(|(node, [left, right])| format!("{node} = ({left}, {right})"))
+        .collect();
+    format!("{directions}\n\n{}", nodes.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part2};
+    use aoc_core::Answer;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(name(0, 3), "BBB");
+        assert_eq!(name(24 * 24 * 24, 3), "CBBB");
+        let sizes = [(String::from("directions"), 11)];
+        for seed in 0..5 {
+            let input = GENERATOR.generate(seed, &sizes).unwrap();
+            let Ok(Answer::Unsigned(steps)) = part1(&input) else {
+                panic!("part 1 failed");
+            };
+            assert_eq!(steps % 11, 0);
+            let Ok(Answer::Unsigned(steps)) = part2(&input) else {
+                panic!("part 2 failed");
+            };
+            let primes: usize = PRIMES.iter().filter(|p| steps % (11 * *p) == 0).count();
+            assert_eq!(primes, 6);
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::string::String;
+
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::{Answer, DayError, Part};
+use aoc_parse::lines;
+use gcd::Gcd;
+
+use combine::{
+    choice, many1,
+    parser::char::{char, letter, newline, string},
+    ParseError, Parser, Stream,
+};
+
+pub mod generator;
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct Step {
+    left: String,
+    right: String,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+enum Direction {
+    Left,
+    Right,
+}
+
+fn node<Input>() -> impl Parser<Input, Output = (String, String, String)>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    (
+        many1(letter()).skip(string(" = (")),
+        many1(letter()).skip(string(", ")),
+        many1(letter()).skip(char(')')),
+    )
+        .map(|(entry, left, right): (String, String, String)| (entry, left, right))
+}
+
+fn network<Input>() -> impl Parser<Input, Output = (Vec<Direction>, HashMap<String, Step>)>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    (
+        many1(choice((
+            char('L').map(|_| Direction::Left),
+            char('R').map(|_| Direction::Right),
+        )))
+        .skip(newline()),
+        newline(),
+        lines(node()),
+    )
+        .map(
+            |(directions, _, nodes): (Vec<Direction>, _, Vec<(String, String, String)>)| {
+                let mut network: HashMap<String, Step> = HashMap::new();
+                nodes.into_iter().for_each(|(entry, left, right)| {
+                    network.insert(entry, Step { left, right });
+                });
+                (directions, network)
+            },
+        )
+}
+
+fn step<'a>(network: &'a HashMap<String, Step>, node: &str) -> Result<&'a Step, DayError> {
+    network
+        .get(node)
+        .ok_or_else(|| DayError::invalid(format!("node {node} is not in the network")))
+}
+
+type Network = (Vec<Direction>, HashMap<String, Step>);
+
+fn parse(input: &str) -> Result<Network, DayError> {
+    aoc_parse::parse(network(), input).map_err(DayError::from)
+}
+
+/// Steps from `start` to the first node accepted by `is_end`.
+fn steps_to_end(
+    directions: &[Direction],
+    network: &HashMap<String, Step>,
+    start: &str,
+    is_end: impl Fn(&str) -> bool,
+) -> Result<usize, DayError> {
+    // a walk that reaches the same node at the same instruction twice is stuck in a loop
+    let mut visited: HashSet<(&str, usize)> = HashSet::new();
+    let mut current = start;
+
+    for (num_steps, (i, dir)) in directions.iter().enumerate().cycle().enumerate() {
+        if is_end(current) {
+            return Ok(num_steps);
+        }
+        if !visited.insert((current, i)) {
+            return Err(DayError::invalid(format!(
+                "the walk from {start} loops without reaching an end"
+            )));
+        }
+        let Step { left, right } = step(network, current)?;
+        current = match dir {
+            Direction::Left => left,
+            Direction::Right => right,
+        };
+    }
+    Err(DayError::invalid("no instructions to follow"))
+}
+
+fn walk((directions, network): Network) -> Result<Answer, DayError> {
+    steps_to_end(&directions, &network, "AAA", |node| node == "ZZZ").map(Answer::from)
+}
+
+fn ghost_walk((directions, network): Network) -> Result<Answer, DayError> {
+    let startpoints: Vec<&str> = network
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .map(String::as_str)
+        .collect();
+    let num_endpoints = network.keys().filter(|node| node.ends_with('Z')).count();
+    if startpoints.len() != num_endpoints {
+        return Err(DayError::invalid(format!(
+            "{} start nodes for {num_endpoints} end nodes",
+            startpoints.len()
+        )));
+    }
+
+    // After observation of the data, it seems that every path loops back to its endpoint
+    // after as many steps as it took to first reach it
+    let loop_length = startpoints
+        .into_iter()
+        .map(|start| steps_to_end(&directions, &network, start, |node| node.ends_with('Z')))
+        .collect::<Result<Vec<usize>, DayError>>()?;
+
+    Ok(loop_length
+        .into_iter()
+        .fold(1, |acc: usize, v| acc / acc.gcd(v) * v)
+        .into())
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    parse(input).and_then(walk)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    parse(input).and_then(ghost_walk)
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    match part {
+        1 => bench::measure(input, runs, parse, walk),
+        _ => bench::measure(input, runs, parse, ghost_walk),
+    }
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use combine::EasyParser;
+
+    use super::*;
+    #[test]
+    fn test_parse() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let ((directions, network), rest) = network().easy_parse(input).unwrap();
+        let expected = network.get("AAA");
+        println!("{:?}", network);
+        println!("{:?}", rest);
+
+        assert_eq!(
+            directions,
+            vec![Direction::Left, Direction::Left, Direction::Right]
+        );
+        assert_eq!(
+            expected.unwrap(),
+            &Step {
+                left: String::from("BBB"),
+                right: String::from("BBB"),
+            },
+        );
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_trailing_newline() {
+        let input = format!("{INPUT}\n");
+        assert_eq!(part1(&input), part1(INPUT));
+        assert_eq!(part2(&input), part2(INPUT));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(part1(input), Ok(Answer::Unsigned(6)));
+    }
+
+    #[test]
+    fn test_part1_loops() {
+        let input = "L
+
+AAA = (AAA, AAA)
+ZZZ = (ZZZ, ZZZ)";
+        assert!(matches!(part1(input), Err(DayError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "LR
+
+AAA = (AAB, XXX)
+AAB = (XXX, AAZ)
+AAZ = (AAB, XXX)
+BBA = (BBB, XXX)
+BBB = (BBC, BBC)
+BBC = (BBZ, BBZ)
+BBZ = (BBB, BBB)
+XXX = (XXX, XXX)";
+        assert_eq!(part2(input), Ok(Answer::Unsigned(6)));
+    }
+
+    #[test]
+    fn test_part2_unpaired() {
+        let input = "LR
+
+AAA = (ZZZ, ZZZ)
+BBA = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert!(matches!(part2(input), Err(DayError::Invalid(_))));
+    }
+}
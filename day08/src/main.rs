@@ -0,0 +1,10 @@
+use anyhow::Result;
+use aoc_core::input;
+use clap::Parser;
+use std::process::ExitCode;
+
+fn main() -> Result<ExitCode> {
+    let opts = day08::Opts::parse();
+    let input = input::read(opts.input.as_ref(), day08::INPUT)?;
+    Ok(aoc_core::run(opts.part, &input, day08::part1, day08::part2))
+}
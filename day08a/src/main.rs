@@ -1,4 +0,0 @@
-fn main() {
-    let out = day08a::compute(include_str!("./input.txt"));
-    println!("Result is : {}", out);
-}
@@ -0,0 +1 @@
+aoc_core::criterion_benches!(day09);
@@ -0,0 +1,67 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "lines",
+            default: 200,
+            range: 1..=100_000_000,
+            help: "number of histories",
+        },
+        Param {
+            name: "length",
+            default: 21,
+            range: 3..=60,
+            help: "values in each history",
+        },
+    ],
+    generate,
+};
+
+/// `n` choose `k`, exact for the small values used here.
+fn binomial(n: usize, k: usize) -> isize {
+    if k > n {
+        return 0;
+    }
+    (0..k).fold(1, |acc, i| acc * (n - i) as isize / (i + 1) as isize)
+}
+
+/// Values of a random polynomial written in the binomial basis, so that each row of differences
+/// is again small and the last row of zeros is reached before running out of values.
+fn history(rng: &mut Rng, length: usize) -> String {
+    let degree = rng.range(0..=(length - 2).min(10));
+    let coefficients: Vec<isize> = (0..=degree)
+        .map(|_| rng.range(0..=20) as isize - 10)
+        .collect();
+    (0..length)
+        .map(|x| {
+            let value: isize = coefficients
+                .iter()
+                .enumerate()
+                .map(|(k, c)| c * binomial(x, k))
+                .sum();
+            value.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let length = sizes.get("length");
+    (0..sizes.get("lines"))
+        .map(|_| history(rng, length))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(5, 2), 10);
+        assert_eq!(binomial(3, 0), 1);
+        assert_eq!(binomial(1, 3), 0);
+    }
+}
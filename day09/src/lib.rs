@@ -0,0 +1,235 @@
+//use ::polyfit_rs::polyfit_rs::polyfit;
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::stream::{LineSolver, LineSum};
+use aoc_core::{Answer, DayError, Part};
+use aoc_parse::{lines, list, signed};
+use combine::{ParseError, Parser, Stream};
+//use polyfit_rs::polyfit_rs;
+
+pub mod generator;
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+    /// Read the input line by line instead of loading it whole
+    #[clap(long)]
+    pub stream: bool,
+}
+
+// pub fn compute_next(measures: &[isize]) -> f64 {
+//     let measures: Vec<f64> = measures.iter().map(|x| *x as f64).collect();
+//     let x: Vec<f64> = (0..measures.len()).map(|x| x as f64).collect();
+//     let poly = polyfit(&x[0..9], &measures[0..9], 8).unwrap();
+//     println!("{:?}", measures.len());
+//     extrapolate(poly, measures.len() as f64)
+// }
+
+// pub fn extrapolate(poly: Vec<f64>, x: f64) -> f64 {
+//     let mut res = 0.;
+//     for (i, f) in poly.iter().enumerate() {
+//         res += f * x.powi(i.try_into().unwrap());
+//     }
+//     res
+// }
+
+/// Differences between consecutive values, and whether they are all 0.
+pub fn next_row(row: Vec<isize>) -> (Vec<isize>, bool) {
+    let differences: Vec<isize> = row.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let finish = differences.iter().all(|&diff| diff == 0);
+    (differences, finish)
+}
+
+pub fn extrapolate_next(row: Vec<isize>) -> isize {
+    let mut finish = false;
+    let mut last_values: Vec<isize> = vec![];
+    let mut res: isize = row.last().copied().unwrap_or(0);
+    let mut current_row = row;
+
+    while !finish {
+        (current_row, finish) = next_row(current_row);
+        last_values.push(current_row.last().copied().unwrap_or(0));
+    }
+
+    res += last_values.iter().sum::<isize>();
+    res
+}
+
+pub fn extrapolate_previous(row: Vec<isize>) -> isize {
+    let mut finish = false;
+    let mut last_values: Vec<isize> = vec![];
+    let first: isize = row.first().copied().unwrap_or(0);
+    let mut current_row = row;
+
+    while !finish {
+        (current_row, finish) = next_row(current_row);
+        last_values.push(current_row.first().copied().unwrap_or(0));
+    }
+
+    last_values = last_values.into_iter().collect();
+    let mut res = 0;
+    for v in last_values.iter().rev() {
+        res = v - res;
+    }
+    first - res
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<isize>>, DayError> {
+    aoc_parse::parse(measures(), input).map_err(DayError::from)
+}
+
+fn extrapolate(part: u8) -> fn(Vec<isize>) -> isize {
+    match part {
+        1 => extrapolate_next,
+        _ => extrapolate_previous,
+    }
+}
+
+fn solve(oasis_measures: Vec<Vec<isize>>, part: u8) -> Answer {
+    oasis_measures
+        .into_iter()
+        .map(extrapolate(part))
+        .sum::<isize>()
+        .into()
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    Ok(solve(parse(input)?, 1))
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    Ok(solve(parse(input)?, 2))
+}
+
+fn measure_line(line: &str) -> Result<Vec<isize>, DayError> {
+    aoc_parse::parse(list(signed()), line).map_err(DayError::from)
+}
+
+pub fn stream(part: u8) -> Box<dyn LineSolver> {
+    match part {
+        1 => Box::new(LineSum::new(|line| {
+            measure_line(line).map(extrapolate_next)
+        })),
+        _ => Box::new(LineSum::new(|line| {
+            measure_line(line).map(extrapolate_previous)
+        })),
+    }
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    bench::measure(input, runs, parse, |measures| Ok(solve(measures, part)))
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, parse)
+}
+
+fn measures<Input>() -> impl Parser<Input, Output = Vec<Vec<isize>>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    lines(list(signed()))
+}
+
+//pub fn compute(input: &str) -> usize {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use combine::EasyParser;
+    use polyfit_rs::polyfit_rs;
+
+    #[test]
+    fn test_polyfit() {
+        let x = [0., 1., 2., 3., 4.];
+        let y = [1., 4., 7., 10., 13.];
+        let rs = polyfit_rs::polyfit(&x, &y, 3).unwrap();
+        println!("{:?}", rs);
+
+        fn extrapolate(poly: Vec<f64>, x: f64) -> f64 {
+            let mut res = 0.;
+            for (i, f) in poly.iter().enumerate() {
+                res += f * x.powi(i.try_into().unwrap());
+            }
+            res
+        }
+        println!("{:?}", extrapolate(rs, 5.));
+        // assert!(false);
+    }
+
+    #[test]
+    fn test_parse_measures() {
+        let input = "-7 -13 -20 -17 16 108
+-9 -7 2 18 41 71";
+        let (output, rest) = measures().easy_parse(input).unwrap();
+        assert_eq!(
+            output,
+            vec![
+                vec![-7, -13, -20, -17, 16, 108],
+                vec![-9, -7, 2, 18, 41, 71]
+            ]
+        );
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_rounding() {
+        //let f1: f64 = -15667.999998316609;
+        let f2: f64 = 413948.00000249385;
+        let f3: f64 = 68151.99999870891;
+
+        //assert_eq!(f1.round() as i64, -15666);
+        assert_eq!(f2.round() as i64, 413948);
+        assert_eq!(f3.round() as i64, 68152);
+    }
+
+    #[test]
+    fn test_extrapolate_part1() {
+        let input = vec![10, 13, 16, 21, 30, 45];
+        let output = extrapolate_next(input);
+
+        assert_eq!(output, 68);
+    }
+
+    #[test]
+    fn test_extrapolate_part2() {
+        let input = vec![10, 13, 16, 21, 30, 45];
+        let output = extrapolate_previous(input);
+
+        assert_eq!(output, 5);
+    }
+
+    #[test]
+    fn test_short_rows() {
+        assert_eq!(
+            (extrapolate_next(vec![5]), extrapolate_previous(vec![5])),
+            (5, 5)
+        );
+        assert_eq!(
+            (extrapolate_next(vec![]), extrapolate_previous(vec![])),
+            (0, 0)
+        );
+        assert_eq!(part1("5\n1 3"), Ok(Answer::Signed(10)));
+    }
+
+    #[test]
+    fn test_stream() {
+        let answers = aoc_core::stream::solve(&[1, 2], INPUT.as_bytes(), stream).unwrap();
+        assert_eq!(answers, vec![part1(INPUT).unwrap(), part2(INPUT).unwrap()]);
+    }
+
+    #[test]
+    fn test_stream_trailing_blank_line() {
+        let input = format!("{INPUT}\n\n");
+        let answers = aoc_core::stream::solve(&[1, 2], input.as_bytes(), stream).unwrap();
+        assert_eq!(answers, vec![part1(INPUT).unwrap(), part2(INPUT).unwrap()]);
+    }
+}
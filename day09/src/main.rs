@@ -0,0 +1,14 @@
+use anyhow::Result;
+use aoc_core::{input, stream};
+use clap::Parser;
+use std::process::ExitCode;
+
+fn main() -> Result<ExitCode> {
+    let opts = day09::Opts::parse();
+    if opts.stream {
+        let reader = input::open(opts.input.as_ref(), day09::INPUT)?;
+        return Ok(stream::run(opts.part, reader, day09::stream));
+    }
+    let input = input::read(opts.input.as_ref(), day09::INPUT)?;
+    Ok(aoc_core::run(opts.part, &input, day09::part1, day09::part2))
+}
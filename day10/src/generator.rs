@@ -0,0 +1,167 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+use aoc_grid::{Grid, Pos};
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "width",
+            default: 140,
+            range: 3..=10_000,
+            help: "number of columns",
+        },
+        Param {
+            name: "height",
+            default: 140,
+            range: 3..=10_000,
+            help: "number of rows",
+        },
+        Param {
+            name: "fill",
+            default: 40,
+            range: 1..=90,
+            help: "share of the map inside the loop, in percent",
+        },
+    ],
+    generate,
+};
+
+/// Neighbours of a cell in clockwise order, starting north.
+const AROUND: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// Whether `cell` can join the shape while keeping it without holes and without two cells
+/// touching by a corner only: its occupied neighbours must form a single run around it.
+fn can_add(shape: &Grid<bool>, cell: Pos) -> bool {
+    let occupied: Vec<bool> = AROUND
+        .iter()
+        .map(|&d| shape.offset(cell, d).is_some_and(|pos| shape[pos]))
+        .collect();
+    let runs = (0..8)
+        .filter(|&i| occupied[i] && !occupied[(i + 7) % 8])
+        .count();
+    runs == 1
+}
+
+/// Random shape of cells grown from a single one. Its outline is a simple loop.
+fn shape(rng: &mut Rng, height: usize, width: usize, fill: usize) -> Grid<bool> {
+    let mut shape = Grid::filled(height, width, false);
+    let start = (rng.below(height), rng.below(width));
+    shape[start] = true;
+    let mut size = 1;
+    let mut frontier: Vec<Pos> = shape.neighbours4(start).collect();
+    while size * 100 < height * width * fill && !frontier.is_empty() {
+        let cell = frontier.swap_remove(rng.below(frontier.len()));
+        if shape[cell] || !can_add(&shape, cell) {
+            continue;
+        }
+        shape[cell] = true;
+        size += 1;
+        frontier.extend(shape.neighbours4(cell).filter(|&pos| !shape[pos]));
+    }
+    shape
+}
+
+/// North, east, south and west connections of every tile, the tiles being the corners of the
+/// cells of the shape and the loop following its outline.
+fn outline(shape: &Grid<bool>) -> Grid<[bool; 4]> {
+    let mut pipes = Grid::filled(shape.height() + 1, shape.width() + 1, [false; 4]);
+    let outside = |pos: Option<Pos>| pos.is_none_or(|pos| !shape[pos]);
+    for ((r, c), &inside) in shape.iter() {
+        if !inside {
+            continue;
+        }
+        if outside(shape.offset((r, c), (-1, 0))) {
+            pipes[(r, c)][1] = true;
+            pipes[(r, c + 1)][3] = true;
+        }
+        if outside(shape.offset((r, c), (1, 0))) {
+            pipes[(r + 1, c)][1] = true;
+            pipes[(r + 1, c + 1)][3] = true;
+        }
+        if outside(shape.offset((r, c), (0, -1))) {
+            pipes[(r, c)][2] = true;
+            pipes[(r + 1, c)][0] = true;
+        }
+        if outside(shape.offset((r, c), (0, 1))) {
+            pipes[(r, c + 1)][2] = true;
+            pipes[(r + 1, c + 1)][0] = true;
+        }
+    }
+    pipes
+}
+
+fn tile(rng: &mut Rng, pipe: [bool; 4]) -> char {
+    match pipe {
+        [true, false, true, false] => '|',
+        [false, true, false, true] => '-',
+        [true, true, false, false] => 'L',
+        [true, false, false, true] => 'J',
+        [false, false, true, true] => '7',
+        [false, true, true, false] => 'F',
+        _ => *rng.choose(&['|', '-', 'L', 'J', '7', 'F', '.', '.', '.']),
+    }
+}
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let (height, width) = (sizes.get("height"), sizes.get("width"));
+    let pipes = outline(&shape(rng, height - 1, width - 1, sizes.get("fill")));
+    let mut maze = pipes.map(|_| '.');
+    for (pos, &pipe) in pipes.iter() {
+        maze[pos] = tile(rng, pipe);
+    }
+
+    // the start is a random tile of the loop, with no other pipe leading to it
+    let on_loop: Vec<Pos> = pipes
+        .iter()
+        .filter(|(_, pipe)| pipe.contains(&true))
+        .map(|(pos, _)| pos)
+        .collect();
+    let start = *rng.choose(&on_loop);
+    maze[start] = 'S';
+    for pos in pipes.neighbours4(start) {
+        if !pipes[pos].contains(&true) {
+            maze[pos] = '.';
+        }
+    }
+    maze.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part2};
+    use aoc_core::Answer;
+
+    #[test]
+    fn test_generate() {
+        let sizes = [(String::from("width"), 30), (String::from("height"), 20)];
+        for seed in 0..10 {
+            // same shape as the one drawn by the generator
+            let shape = shape(&mut Rng::new(seed), 19, 29, 40);
+            let on_loop = outline(&shape)
+                .iter()
+                .filter(|(_, pipe)| pipe.contains(&true))
+                .count();
+            let inside = (1..19)
+                .flat_map(|r| (1..29).map(move |c| (r, c)))
+                .filter(|&(r, c)| {
+                    [(r - 1, c - 1), (r - 1, c), (r, c - 1), (r, c)]
+                        .iter()
+                        .all(|&cell| shape[cell])
+                })
+                .count();
+
+            let input = GENERATOR.generate(seed, &sizes).unwrap();
+            assert_eq!(part1(&input), Ok(Answer::Unsigned(on_loop / 2)));
+            assert_eq!(part2(&input), Ok(Answer::Unsigned(inside)));
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use anyhow::anyhow;
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::{Answer, DayError, Part};
+use aoc_grid::{grid, Grid, Pos};
+use combine::{
+    choice, error::StreamError, parser::char::char, stream::StreamErrorFor, ParseError, Parser,
+    Stream,
+};
+
+pub mod generator;
+pub mod part1;
+pub mod part2;
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Tile {
+    #[default]
+    Ground,
+    Start,
+    NS,
+    EW,
+    NE,
+    NW,
+    SE,
+    SW,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Direction {
+    N,
+    S,
+    E,
+    W,
+}
+
+impl From<(isize, isize)> for Direction {
+    fn from(item: (isize, isize)) -> Self {
+        match item {
+            (-1, 0) => Direction::N,
+            (1, 0) => Direction::S,
+            (0, -1) => Direction::W,
+            (0, 1) => Direction::E,
+            _ => unreachable!("Not a valid direction!"),
+        }
+    }
+}
+
+impl From<[Direction; 2]> for Tile {
+    fn from(item: [Direction; 2]) -> Self {
+        if item.contains(&Direction::N) && item.contains(&Direction::S) {
+            return Tile::NS;
+        }
+        if item.contains(&Direction::N) && item.contains(&Direction::E) {
+            return Tile::NE;
+        }
+        if item.contains(&Direction::N) && item.contains(&Direction::W) {
+            return Tile::NW;
+        }
+        if item.contains(&Direction::S) && item.contains(&Direction::E) {
+            return Tile::SE;
+        }
+        if item.contains(&Direction::S) && item.contains(&Direction::W) {
+            return Tile::SW;
+        }
+        if item.contains(&Direction::E) && item.contains(&Direction::W) {
+            return Tile::EW;
+        }
+        unreachable!("Impossible Tile from directions : {:?}", item);
+    }
+}
+
+impl From<Direction> for (isize, isize) {
+    fn from(val: Direction) -> Self {
+        match val {
+            Direction::N => (-1, 0),
+            Direction::S => (1, 0),
+            Direction::W => (0, -1),
+            Direction::E => (0, 1),
+        }
+    }
+}
+
+fn revert(d: Direction) -> Direction {
+    match d {
+        Direction::N => Direction::S,
+        Direction::E => Direction::W,
+        Direction::S => Direction::N,
+        Direction::W => Direction::E,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Maze {
+    grid: Grid<Tile>,
+    start: Pos,
+}
+
+impl Maze {
+    /// Direction and position after crossing the pipe at `pos`, entered going `incoming_direction`.
+    fn next(&self, incoming_direction: Direction, pos: Pos) -> anyhow::Result<(Direction, Pos)> {
+        let outgoing = match (&self.grid[pos], incoming_direction) {
+            (Tile::NS, Direction::S) | (Tile::SE, Direction::W) | (Tile::SW, Direction::E) => {
+                Direction::S
+            }
+            (Tile::NS, Direction::N) | (Tile::NE, Direction::W) | (Tile::NW, Direction::E) => {
+                Direction::N
+            }
+            (Tile::EW, Direction::E) | (Tile::NE, Direction::S) | (Tile::SE, Direction::N) => {
+                Direction::E
+            }
+            (Tile::EW, Direction::W) | (Tile::NW, Direction::S) | (Tile::SW, Direction::N) => {
+                Direction::W
+            }
+            (Tile::Start, _) => return Err(anyhow!("the loop goes through the start tile twice")),
+            (Tile::Ground, _) => return Err(anyhow!("Can't reach ground!")),
+            (tile, d) => return Err(anyhow!("Tile {tile:?} / Forbidden direction: {d:?}")),
+        };
+        let pos = self
+            .grid
+            .offset(pos, outgoing.into())
+            .ok_or_else(|| anyhow!("the loop leaves the map"))?;
+        Ok((outgoing, pos))
+    }
+
+    fn loop_entry(&self) -> anyhow::Result<(Tile, Direction, Pos)> {
+        let directions = [Direction::N, Direction::S, Direction::W, Direction::E];
+        let mut start_dirs: Vec<Direction> = vec![];
+
+        for neighbor in self.grid.neighbours4(self.start) {
+            for &direction in directions.iter() {
+                if let Ok((incoming_dir, prev_pos)) = self.next(direction, neighbor) {
+                    if prev_pos == self.start {
+                        start_dirs.push(revert(incoming_dir));
+                        if start_dirs.len() == 2 {
+                            return Ok((
+                                Tile::from([start_dirs[0], start_dirs[1]]),
+                                revert(incoming_dir),
+                                neighbor,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        Err(anyhow!("no pipe connects to the start tile"))
+    }
+}
+
+fn tile<Input>() -> impl Parser<Input, Output = Tile>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    choice!(
+        char('|').map(|_| Tile::NS),
+        char('-').map(|_| Tile::EW),
+        char('L').map(|_| Tile::NE),
+        char('J').map(|_| Tile::NW),
+        char('7').map(|_| Tile::SW),
+        char('F').map(|_| Tile::SE),
+        char('.').map(|_| Tile::Ground),
+        char('S').map(|_| Tile::Start)
+    )
+}
+
+fn maze<Input>() -> impl Parser<Input, Output = Maze>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    grid(tile()).and_then(
+        |grid: Grid<Tile>| match grid.position(|tile| tile == &Tile::Start) {
+            None => Err(StreamErrorFor::<Input>::message_static_message(
+                "start tile `S` not found",
+            )),
+            Some(start) => Ok(Maze { grid, start }),
+        },
+    )
+}
+
+/// Maze of the input, which must hold a single start tile.
+fn parse(input: &str) -> Result<Maze, DayError> {
+    let maze = aoc_parse::parse(maze(), input).map_err(DayError::from)?;
+    // `and_then` reports at the start of the grid, so the extra start is located here
+    let extra_start = maze
+        .grid
+        .iter()
+        .find(|&(pos, tile)| tile == &Tile::Start && pos != maze.start)
+        .map(|(pos, _)| pos);
+    match extra_start {
+        Some((row, col)) => Err(DayError::at(
+            row + 1,
+            col + 1,
+            "more than one start tile `S`",
+        )),
+        None => Ok(maze),
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    parse(input).and_then(part1::solve).map(Answer::from)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    parse(input).and_then(part2::solve).map(Answer::from)
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    let solve = if part == 1 {
+        part1::solve
+    } else {
+        part2::solve
+    };
+    bench::measure(input, runs, parse, |maze| solve(maze).map(Answer::from))
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use combine::EasyParser;
+
+    #[test]
+    fn test_parse_maze() {
+        let input = ".....
+.F-7.
+.S.|.
+.L-J.
+.....";
+        let (maze, rest) = maze().easy_parse(input).unwrap();
+        assert_eq!(maze.start, (2, 1));
+        assert_eq!(
+            maze.grid.row(1),
+            &[Tile::Ground, Tile::SE, Tile::EW, Tile::SW, Tile::Ground]
+        );
+        assert!(rest.is_empty());
+        let accepted_values = [
+            (Tile::NS, Direction::N, (1, 1)),
+            (Tile::NS, Direction::S, (3, 1)),
+        ];
+        assert!(accepted_values.contains(&maze.loop_entry().unwrap()));
+    }
+
+    #[test]
+    fn test_trailing_newline() {
+        let input = format!("{INPUT}\n");
+        assert_eq!(part1(&input), part1(INPUT));
+        assert_eq!(part2(&input), part2(INPUT));
+    }
+
+    #[test]
+    fn test_broken_loops() {
+        let lonely = "...\n.S.\n...";
+        assert_eq!(
+            part1(lonely),
+            Err(DayError::invalid("no pipe connects to the start tile"))
+        );
+        assert!(part2(lonely).is_err());
+        let open = "S-7\n|.|\nL--";
+        assert!(part1(open).is_err() && part2(open).is_err());
+    }
+
+    #[test]
+    fn test_several_starts() {
+        assert_eq!(
+            parse("SS\nSS\n").map(|maze| maze.start),
+            Err(DayError::at(1, 2, "more than one start tile `S`"))
+        );
+    }
+}
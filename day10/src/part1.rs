@@ -0,0 +1,36 @@
+use aoc_core::DayError;
+
+use crate::Maze;
+
+/// Steps to the farthest tile of the loop, half its length.
+pub(crate) fn solve(maze: Maze) -> Result<usize, DayError> {
+    let mut cnt = 1;
+    let (_, mut direction, mut pos) = maze
+        .loop_entry()
+        .map_err(|e| DayError::invalid(e.to_string()))?;
+
+    while pos != maze.start {
+        (direction, pos) = maze
+            .next(direction, pos)
+            .map_err(|e| DayError::invalid(e.to_string()))?;
+        cnt += 1
+    }
+    Ok(cnt / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute() {
+        //input 1
+        let input = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+        let res = crate::parse(input).and_then(solve);
+        assert_eq!(res, Ok(8));
+    }
+}
@@ -0,0 +1,229 @@
+use anyhow::Result;
+use aoc_core::DayError;
+use aoc_grid::{Grid, Pos};
+
+use crate::{Direction, Maze, Tile};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum State {
+    Unknown,
+    Wall,
+    Inside,
+    Outside,
+}
+
+impl Direction {
+    fn parralel(&self, other: &Direction) -> bool {
+        let d1 = [Direction::N, Direction::S];
+        let d2 = [Direction::E, Direction::W];
+        if ((d1.contains(self)) && (d1.contains(other)))
+            | ((d2.contains(self)) && (d2.contains(other)))
+        {
+            return true;
+        }
+        false
+    }
+}
+
+impl Tile {
+    fn perpendicular(&self) -> Vec<Direction> {
+        match self {
+            Tile::NS => vec![Direction::E],
+            Tile::EW => vec![Direction::S],
+            Tile::NE => vec![Direction::S, Direction::W],
+            Tile::NW => vec![Direction::S, Direction::E],
+            Tile::SW => vec![Direction::N, Direction::E],
+            Tile::SE => vec![Direction::N, Direction::W],
+            _ => unreachable!("Perpendicular problem"),
+        }
+    }
+
+    fn next_perpendicular(&self, p: Direction) -> Direction {
+        match (self, p) {
+            // No direction change
+            (Tile::NS, p) => p,
+            (Tile::EW, p) => p,
+            // Direction change NE
+            (Tile::NE, Direction::N) => Direction::E,
+            (Tile::NE, Direction::S) => Direction::W,
+            (Tile::NE, Direction::E) => Direction::N,
+            (Tile::NE, Direction::W) => Direction::S,
+            // Direction change NW
+            (Tile::NW, Direction::N) => Direction::W,
+            (Tile::NW, Direction::S) => Direction::E,
+            (Tile::NW, Direction::E) => Direction::S,
+            (Tile::NW, Direction::W) => Direction::N,
+            // Direction change SE
+            (Tile::SE, Direction::N) => Direction::W,
+            (Tile::SE, Direction::S) => Direction::E,
+            (Tile::SE, Direction::E) => Direction::S,
+            (Tile::SE, Direction::W) => Direction::N,
+            // Direction change SW
+            (Tile::SW, Direction::N) => Direction::E,
+            (Tile::SW, Direction::S) => Direction::W,
+            (Tile::SW, Direction::E) => Direction::N,
+            (Tile::SW, Direction::W) => Direction::S,
+            // Start cell don't change
+            (Tile::Start, p) => p,
+            // Not possible
+            e => unreachable!("Next Perpendicular problem:{:?}", e),
+        }
+    }
+}
+
+/// Side of the loop each tile of the maze lies on.
+#[derive(Debug, Clone)]
+struct Sides {
+    maze: Maze,
+    state: Grid<State>,
+    start_tile: Option<Tile>,
+}
+
+impl Sides {
+    fn new(maze: Maze) -> Self {
+        let state = Grid::filled(maze.grid.height(), maze.grid.width(), State::Unknown);
+        Sides {
+            maze,
+            state,
+            start_tile: None,
+        }
+    }
+
+    fn init_state(&mut self) -> Result<()> {
+        let (start_tile, mut direction, mut pos) = self.maze.loop_entry()?;
+        let mut rightmost: (Pos, (Direction, Direction)) = ((0, 0), (Direction::N, Direction::N));
+
+        self.start_tile = Some(start_tile);
+        // Mark cells around starting point
+        let first_perpendiculars = self.maze.grid[pos].perpendicular();
+        let mut perpendicular: Direction = *first_perpendiculars.first().unwrap();
+        for p in first_perpendiculars.into_iter() {
+            self.mark(pos, p);
+            if p.parralel(&direction) {
+                perpendicular = p;
+            }
+        }
+        // Finish loop while keeping perpendicular direction consistent
+        while pos != self.maze.start {
+            self.state[pos] = State::Wall;
+            if pos.1 >= rightmost.0 .1 {
+                rightmost = (
+                    pos,
+                    (
+                        perpendicular,
+                        self.maze.grid[pos].next_perpendicular(perpendicular),
+                    ),
+                );
+            }
+            (direction, pos) = self.maze.next(direction, pos)?;
+
+            self.mark(pos, perpendicular);
+            perpendicular = self.maze.grid[pos].next_perpendicular(perpendicular);
+            self.mark(pos, perpendicular);
+        }
+        self.mark(pos, perpendicular);
+        self.mark(
+            pos,
+            self.start_tile
+                .as_ref()
+                .unwrap()
+                .next_perpendicular(perpendicular),
+        );
+        if pos.1 >= rightmost.0 .1 {
+            rightmost = (
+                pos,
+                (
+                    perpendicular,
+                    self.start_tile
+                        .as_ref()
+                        .unwrap()
+                        .next_perpendicular(perpendicular),
+                ),
+            );
+        }
+        if (rightmost.1 .0 == Direction::W) | (rightmost.1 .1 == Direction::W) {
+            self.invert_state()
+        }
+        Ok(())
+    }
+
+    fn floodfill(&mut self) {
+        let mut q: Vec<Pos> = vec![];
+        let mut state = State::Unknown;
+
+        for (pos, s) in self.state.iter() {
+            if (s == &State::Inside) | (s == &State::Outside) {
+                q.push(pos);
+                state = s.clone();
+            }
+        }
+
+        while let Some(pos) = q.pop() {
+            for n in self.maze.grid.neighbours4(pos) {
+                if self.state[n] == State::Unknown {
+                    self.state[n] = state.clone();
+                    q.push(n);
+                }
+            }
+        }
+    }
+
+    fn invert_state(&mut self) {
+        self.state = self.state.map(|s| match s {
+            State::Outside => State::Inside,
+            State::Inside => State::Outside,
+            s => s.clone(),
+        });
+    }
+
+    fn mark(&mut self, wall_pos: Pos, direction: Direction) {
+        self.state[wall_pos] = State::Wall;
+        if let Some(pos) = self.state.offset(wall_pos, direction.into()) {
+            if self.state[pos] != State::Wall {
+                self.state[pos] = State::Outside;
+            }
+        }
+    }
+
+    fn count(&self, state: State) -> usize {
+        self.state.iter().filter(|(_, s)| *s == &state).count()
+    }
+}
+/// Tiles enclosed by the loop.
+pub(crate) fn solve(maze: Maze) -> Result<usize, DayError> {
+    let mut sides = Sides::new(maze);
+    sides
+        .init_state()
+        .map_err(|e| DayError::invalid(e.to_string()))?;
+    sides.floodfill();
+    let cnt_unknown = sides.count(State::Unknown);
+    let cnt_inside = sides.count(State::Inside);
+    if cnt_inside > 0 {
+        return Ok(cnt_inside);
+    }
+    Ok(cnt_unknown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_init_state() {
+        //input 1
+        let input = ".............
+.S---------7.
+.|...F----7|.
+.|...|....||.
+.|...|....||.
+.|...L-7F-J|.
+.|.....||..|.
+.L-----JL--J.
+.............";
+        let mut sides = Sides::new(parse(input).unwrap());
+        sides.init_state().unwrap();
+        assert_eq!(sides.count(State::Wall), 50);
+        assert_eq!(parse(input).and_then(solve), Ok(19));
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+use aoc_grid::Grid;
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "width",
+            default: 140,
+            range: 1..=100_000,
+            help: "number of columns",
+        },
+        Param {
+            name: "height",
+            default: 140,
+            range: 1..=100_000,
+            help: "number of rows",
+        },
+        Param {
+            name: "density",
+            default: 3,
+            range: 0..=100,
+            help: "chance of a tile being a galaxy, in percent",
+        },
+    ],
+    generate,
+};
+
+/// Galaxies are scattered at random, one row and one column out of ten being left empty to be
+/// expanded.
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let (height, width) = (sizes.get("height"), sizes.get("width"));
+    let empty_rows: Vec<bool> = (0..height).map(|_| rng.chance(10)).collect();
+    let empty_columns: Vec<bool> = (0..width).map(|_| rng.chance(10)).collect();
+    let mut image = Grid::filled(height, width, '.');
+    for row in 0..height {
+        for col in 0..width {
+            if !empty_rows[row] && !empty_columns[col] && rng.chance(sizes.get("density")) {
+                image[(row, col)] = '#';
+            }
+        }
+    }
+    image.to_string()
+}
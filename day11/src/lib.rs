@@ -0,0 +1,162 @@
+//use ::polyfit_rs::polyfit_rs::polyfit;
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::{Answer, DayError, Part};
+use aoc_grid::{grid, Grid};
+use combine::{choice, parser::char::char, ParseError, Parser, Stream};
+
+pub mod generator;
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+}
+
+#[derive(Debug, Clone)]
+struct Image(Grid<bool>);
+
+fn manhattan(p1: (usize, usize), p2: (usize, usize)) -> usize {
+    let (x1, y1) = p1;
+    let (x2, y2) = p2;
+    x1.abs_diff(x2) + y1.abs_diff(y2)
+}
+
+impl Image {
+    fn empty_rows(&self) -> Vec<usize> {
+        (0..self.0.height())
+            .filter(|&i| self.0.row(i).iter().all(|&x| !x))
+            .collect()
+    }
+
+    fn empty_columns(&self) -> Vec<usize> {
+        (0..self.0.width())
+            .filter(|&j| self.0.column(j).all(|&x| !x))
+            .collect()
+    }
+
+    fn get_true_position(
+        &self,
+        pos: (usize, usize),
+        galaxy_rows: &[usize],
+        galaxy_cols: &[usize],
+        distortion: usize,
+    ) -> (usize, usize) {
+        (
+            pos.0
+                + (galaxy_rows
+                    .iter()
+                    .filter(|&&x| x < pos.0)
+                    .collect::<Vec<_>>()
+                    .len()
+                    * (distortion - 1)),
+            pos.1
+                + (galaxy_cols
+                    .iter()
+                    .filter(|&&x| x < pos.1)
+                    .collect::<Vec<_>>()
+                    .len()
+                    * (distortion - 1)),
+        )
+    }
+
+    fn compute_shortest_paths(&self, distortion: usize) -> usize {
+        //columns containing no galaxy
+        let galaxy_cols = self.empty_columns();
+        //rows containing no galaxy
+        let galaxy_rows = self.empty_rows();
+        //galaxies positions
+        let mut galaxy_pos: Vec<(usize, usize)> = self
+            .0
+            .iter()
+            .filter(|(_, &galaxy)| galaxy)
+            .map(|(pos, _)| self.get_true_position(pos, &galaxy_rows, &galaxy_cols, distortion))
+            .collect();
+        let mut cnt: usize = 0;
+        while let Some(pos) = galaxy_pos.pop() {
+            cnt += galaxy_pos
+                .iter()
+                .map(|other| manhattan(pos, *other))
+                .sum::<usize>();
+        }
+        cnt
+    }
+}
+
+fn parse(input: &str) -> Result<Image, DayError> {
+    aoc_parse::parse(image(), input).map_err(DayError::from)
+}
+
+/// Each empty row or column counts as `distortion` rows or columns.
+fn distortion(part: u8) -> usize {
+    match part {
+        1 => 2,
+        _ => 1000000,
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    Ok(parse(input)?.compute_shortest_paths(distortion(1)).into())
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    Ok(parse(input)?.compute_shortest_paths(distortion(2)).into())
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    bench::measure(input, runs, parse, |image| {
+        Ok(image.compute_shortest_paths(distortion(part)).into())
+    })
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, parse)
+}
+
+fn image<Input>() -> impl Parser<Input, Output = Image>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    grid(symbol()).map(Image)
+}
+
+fn symbol<Input>() -> impl Parser<Input, Output = bool>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    choice!(char('.').map(|_| false), char('#').map(|_| true))
+}
+
+//pub fn compute(input: &str) -> usize {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use combine::EasyParser;
+
+    #[test]
+    fn test_parse_image() {
+        let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+        let (output, rest) = image().easy_parse(input).unwrap();
+        println!("{:?}", output);
+        assert!(rest.is_empty());
+        assert_eq!(output.compute_shortest_paths(2), 374);
+    }
+}
@@ -0,0 +1,10 @@
+use anyhow::Result;
+use aoc_core::input;
+use clap::Parser;
+use std::process::ExitCode;
+
+fn main() -> Result<ExitCode> {
+    let opts = day11::Opts::parse();
+    let input = input::read(opts.input.as_ref(), day11::INPUT)?;
+    Ok(aoc_core::run(opts.part, &input, day11::part1, day11::part2))
+}
@@ -0,0 +1,77 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "lines",
+            default: 1000,
+            range: 1..=100_000_000,
+            help: "number of rows of springs",
+        },
+        Param {
+            name: "length",
+            default: 20,
+            range: 1..=100,
+            help: "maximum number of springs in a row",
+        },
+    ],
+    generate,
+};
+
+/// Draws a row of springs then hides some of them, so that at least one arrangement fits.
+fn row(rng: &mut Rng, length: usize) -> String {
+    let length = rng.range(1..=length);
+    let mut springs = String::new();
+    let mut groups = vec![];
+    loop {
+        let gap = match springs.is_empty() {
+            true => rng.range(0..=2),
+            false => rng.range(1..=3),
+        };
+        if springs.len() + gap >= length {
+            break;
+        }
+        springs.push_str(&".".repeat(gap));
+        let group = rng.range(1..=(length - springs.len()).min(5));
+        springs.push_str(&"#".repeat(group));
+        groups.push(group);
+    }
+    springs.push_str(&".".repeat(length - springs.len()));
+    if groups.is_empty() {
+        springs.replace_range(..1, "#");
+        groups.push(1);
+    }
+    let springs: String = springs
+        .chars()
+        .map(|c| if rng.chance(40) { '?' } else { c })
+        .collect();
+    let groups: Vec<String> = groups.iter().map(usize::to_string).collect();
+    format!("{springs} {}", groups.join(","))
+}
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let length = sizes.get("length");
+    (0..sizes.get("lines"))
+        .map(|_| row(rng, length))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part2};
+    use aoc_core::Answer;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let input = GENERATOR.generate(seed, &[]).unwrap();
+            for line in input.lines() {
+                // every row has an arrangement
+                assert_ne!(part1(line), Ok(Answer::Unsigned(0)), "{line}");
+            }
+            assert!(part2(&input).is_ok());
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::stream::{LineSolver, LineSum};
+use aoc_core::{Answer, DayError, Part};
+use aoc_parse::{lines, unsigned};
+use combine::{
+    error::StreamError,
+    many1,
+    parser::char::{char, space},
+    sep_by1,
+    stream::StreamErrorFor,
+    ParseError, Parser, Stream,
+};
+
+pub mod generator;
+pub mod part1;
+pub mod part2;
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+    /// Read the input line by line instead of loading it whole
+    #[clap(long)]
+    pub stream: bool,
+}
+
+fn hot_spring<Input>() -> impl Parser<Input, Output = HotSpring>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    (
+        many1::<String, _, _>(char('?').or(char('#').or(char('.')))).skip(space()),
+        sep_by1::<Vec<usize>, _, _, _>(
+            unsigned::<_, usize>().and_then(|n| match n {
+                0 => Err(StreamErrorFor::<Input>::message_static_message(
+                    "damaged groups hold at least one spring",
+                )),
+                n => Ok(n),
+            }),
+            char(','),
+        ),
+    )
+        .map(|(springs, damaged)| HotSpring { springs, damaged })
+}
+
+fn hot_springs<Input>() -> impl Parser<Input, Output = Vec<HotSpring>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    lines(hot_spring())
+}
+
+#[derive(Debug, Clone)]
+pub struct HotSpring {
+    springs: String,
+    damaged: Vec<usize>,
+}
+
+fn parse(input: &str) -> Result<Vec<HotSpring>, DayError> {
+    aoc_parse::parse(hot_springs(), input).map_err(DayError::from)
+}
+
+/// The springs of a single line.
+fn spring(line: &str) -> Result<HotSpring, DayError> {
+    aoc_parse::parse(hot_spring(), line).map_err(DayError::from)
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    parse(input).map(|springs| part1::solve(springs).into())
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    parse(input).map(|springs| part2::solve(springs).into())
+}
+
+pub fn stream(part: u8) -> Box<dyn LineSolver> {
+    match part {
+        1 => Box::new(LineSum::new(part1::arrangements)),
+        _ => Box::new(LineSum::new(part2::arrangements)),
+    }
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    let solve = if part == 1 {
+        part1::solve
+    } else {
+        part2::solve
+    };
+    bench::measure(input, runs, parse, |springs| Ok(solve(springs).into()))
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use combine::EasyParser;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    fn line(springs: &str, damaged: &[usize]) -> String {
+        let damaged: Vec<String> = damaged.iter().map(usize::to_string).collect();
+        format!("{springs} {}", damaged.join(","))
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+        let (output, rest) = hot_springs().easy_parse(input).unwrap();
+        assert!(rest.is_empty());
+        let first_hotspring = output.first().unwrap();
+        assert_eq!(first_hotspring.springs, String::from("???.###"));
+        assert_eq!(first_hotspring.damaged, vec![1, 1, 3]);
+    }
+
+    #[test]
+    fn test_empty_group() {
+        assert_eq!(
+            part2("#.# 1\n??? 0"),
+            Err(DayError::at(
+                2,
+                5,
+                "damaged groups hold at least one spring"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_trailing_newline() {
+        let input = format!("{INPUT}\n");
+        assert_eq!(part1(&input), part1(INPUT));
+        assert_eq!(part2(&input), part2(INPUT));
+    }
+
+    #[test]
+    fn test_stream() {
+        let answers = aoc_core::stream::solve(&[1, 2], INPUT.as_bytes(), stream).unwrap();
+        assert_eq!(answers, vec![part1(INPUT).unwrap(), part2(INPUT).unwrap()]);
+    }
+
+    #[test]
+    fn test_stream_trailing_blank_line() {
+        let input = format!("{INPUT}\n\n");
+        let answers = aoc_core::stream::solve(&[1, 2], input.as_bytes(), stream).unwrap();
+        assert_eq!(answers, vec![part1(INPUT).unwrap(), part2(INPUT).unwrap()]);
+    }
+
+    proptest! {
+        #[test]
+        fn dp_matches_backtracking(springs in "[.#?]{1,12}", damaged in vec(1usize..5, 1..5)) {
+            let line = line(&springs, &damaged);
+            let spring = parse(&line).unwrap().remove(0);
+            prop_assert_eq!(part2::decode(&spring, 1), part1::arrangements(&line).unwrap());
+        }
+
+        #[test]
+        fn dp_matches_backtracking_unfolded(springs in "[.#?]{1,6}", damaged in vec(1usize..4, 1..3)) {
+            let unfolded = line(&[springs.as_str(); 2].join("?"), &damaged.repeat(2));
+            let spring = parse(&line(&springs, &damaged)).unwrap().remove(0);
+            let expected = part1::decode(parse(&unfolded).unwrap().remove(0));
+            prop_assert_eq!(part2::decode(&spring, 2), expected);
+        }
+    }
+}
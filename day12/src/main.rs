@@ -0,0 +1,14 @@
+use anyhow::Result;
+use aoc_core::{input, stream};
+use clap::Parser;
+use std::process::ExitCode;
+
+fn main() -> Result<ExitCode> {
+    let opts = day12::Opts::parse();
+    if opts.stream {
+        let reader = input::open(opts.input.as_ref(), day12::INPUT)?;
+        return Ok(stream::run(opts.part, reader, day12::stream));
+    }
+    let input = input::read(opts.input.as_ref(), day12::INPUT)?;
+    Ok(aoc_core::run(opts.part, &input, day12::part1, day12::part2))
+}
@@ -1,50 +1,16 @@
 use std::string::String;
 
-//use ::polyfit_rs::polyfit_rs::polyfit;
-use combine::{
-    many1,
-    parser::char::{char, digit, newline, space},
-    sep_by1, EasyParser, ParseError, Parser, Stream,
-};
+use aoc_core::DayError;
 
-fn _usize<Input>() -> impl Parser<Input, Output = usize>
-where
-    Input: Stream<Token = char>,
-    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
-{
-    (many1(digit())).map(|number: String| number.parse::<usize>().unwrap())
-}
-
-fn hot_springs<Input>() -> impl Parser<Input, Output = Vec<HotSpring>>
-where
-    Input: Stream<Token = char>,
-    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
-{
-    (sep_by1(
-        (
-            many1::<String, _, _>(char('?').or(char('#').or(char('.')))).skip(space()),
-            sep_by1::<Vec<usize>, _, _, _>(_usize(), char(',')),
-        ),
-        newline(),
-    ),)
-        .map(|(springs,): (Vec<(String, Vec<usize>)>,)| {
-            springs
-                .into_iter()
-                .map(|(springs, damaged)| HotSpring { springs, damaged })
-                .collect::<Vec<HotSpring>>()
-        })
-}
-
-#[derive(Debug)]
-pub struct HotSpring {
-    springs: String,
-    damaged: Vec<usize>,
-}
+use crate::HotSpring;
 
 pub fn decode(spring: HotSpring) -> usize {
-    let remaining_damaged = spring.damaged.repeat(3);
-    let springs = spring.springs.repeat(3);
-    backtrack(springs.chars().next(), springs, remaining_damaged, false)
+    backtrack(
+        spring.springs.chars().next(),
+        spring.springs,
+        spring.damaged,
+        false,
+    )
 }
 
 pub fn backtrack(
@@ -71,10 +37,8 @@ pub fn backtrack(
                     Some(&0) => {
                         remaining_damaged.remove(0);
                     }
-                    Some(_) => {
-                        if contiguous {
-                            return 0;
-                        }
+                    Some(_) if contiguous => {
+                        return 0;
                     }
                     _ => {}
                 };
@@ -112,8 +76,7 @@ pub fn backtrack(
     }
 }
 
-pub fn compute(input: &str) -> usize {
-    let (hot_springs, _) = hot_springs().easy_parse(input).unwrap();
+pub(crate) fn solve(hot_springs: Vec<HotSpring>) -> usize {
     let mut total = 0;
     hot_springs.into_iter().for_each(|s| total += decode(s));
     total
@@ -123,26 +86,14 @@ pub fn compute(input: &str) -> usize {
     //image.compute_shortest_paths(1000000)
 }
 
+/// Arrangements of the springs of a single line.
+pub(crate) fn arrangements(line: &str) -> Result<usize, DayError> {
+    crate::spring(line).map(decode)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use combine::EasyParser;
-
-    #[test]
-    fn test_parse() {
-        let input = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
-
-        let (output, rest) = hot_springs().easy_parse(input).unwrap();
-        assert!(rest.is_empty());
-        let first_hotspring = output.first().unwrap();
-        assert_eq!(first_hotspring.springs, String::from("???.###"));
-        assert_eq!(first_hotspring.damaged, vec![1, 1, 3]);
-    }
 
     #[test]
     fn test_chars() {
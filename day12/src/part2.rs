@@ -1,46 +1,8 @@
 use ndarray::{Array2, Axis};
-use std::string::String;
-
-//use ::polyfit_rs::polyfit_rs::polyfit;
-use combine::{
-    many1,
-    parser::char::{char, digit, newline, space},
-    sep_by1, EasyParser, ParseError, Parser, Stream,
-};
-
-fn _usize<Input>() -> impl Parser<Input, Output = usize>
-where
-    Input: Stream<Token = char>,
-    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
-{
-    (many1(digit())).map(|number: String| number.parse::<usize>().unwrap())
-}
 
-fn hot_springs<Input>() -> impl Parser<Input, Output = Vec<HotSpring>>
-where
-    Input: Stream<Token = char>,
-    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
-{
-    (sep_by1(
-        (
-            many1::<String, _, _>(char('?').or(char('#').or(char('.')))).skip(space()),
-            sep_by1::<Vec<usize>, _, _, _>(_usize(), char(',')),
-        ),
-        newline(),
-    ),)
-        .map(|(springs,): (Vec<(String, Vec<usize>)>,)| {
-            springs
-                .into_iter()
-                .map(|(springs, damaged)| HotSpring { springs, damaged })
-                .collect::<Vec<HotSpring>>()
-        })
-}
+use aoc_core::DayError;
 
-#[derive(Debug)]
-pub struct HotSpring {
-    springs: String,
-    damaged: Vec<usize>,
-}
+use crate::HotSpring;
 
 pub fn could_fit(springs: &str, start_idx: usize, n_damaged: usize) -> bool {
     let end_idx = start_idx + n_damaged;
@@ -103,33 +65,20 @@ pub fn decode(spring: &HotSpring, repeat: usize) -> usize {
     memo.sum_axis(Axis(0))[remaining_damaged.len() - 1]
 }
 
-pub fn compute(input: &str) -> usize {
-    let (hot_springs, _) = hot_springs().easy_parse(input).unwrap();
+pub(crate) fn solve(hot_springs: Vec<HotSpring>) -> usize {
     let mut total = 0;
     hot_springs.into_iter().for_each(|s| total += decode(&s, 5));
     total
 }
 
+/// Arrangements of the springs of a single line.
+pub(crate) fn arrangements(line: &str) -> Result<usize, DayError> {
+    crate::spring(line).map(|spring| decode(&spring, 5))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use combine::EasyParser;
-
-    #[test]
-    fn test_parse() {
-        let input = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
-
-        let (output, rest) = hot_springs().easy_parse(input).unwrap();
-        assert!(rest.is_empty());
-        let first_hotspring = output.first().unwrap();
-        assert_eq!(first_hotspring.springs, String::from("???.###"));
-        assert_eq!(first_hotspring.damaged, vec![1, 1, 3]);
-    }
 
     #[test]
     fn test_could_fit() {
@@ -165,7 +114,7 @@ mod tests {
 ?###???????? 3,2,1
 ?####???.???#? 6,1,2";
 
-        let (output, _) = hot_springs().easy_parse(input).unwrap();
+        let output = crate::parse(input).unwrap();
         let first_hotspring = output.first().unwrap();
         assert_eq!(decode(first_hotspring, 1), 1);
         let last_hotspring = output.last().unwrap();
@@ -0,0 +1,124 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+use aoc_grid::Grid;
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "patterns",
+            default: 100,
+            range: 1..=10_000_000,
+            help: "number of patterns",
+        },
+        Param {
+            name: "size",
+            default: 17,
+            range: 5..=1000,
+            help: "maximum number of rows and columns of a pattern",
+        },
+    ],
+    generate,
+};
+
+/// Cells that differ between the rows mirrored by a line between rows `line - 1` and `line`.
+fn mismatches(pattern: &Grid<bool>, line: usize) -> usize {
+    let size = line.min(pattern.height() - line);
+    (0..size)
+        .map(|k| {
+            let (above, below) = (pattern.row(line - 1 - k), pattern.row(line + k));
+            above.iter().zip(below).filter(|(a, b)| a != b).count()
+        })
+        .sum()
+}
+
+/// Mirror position of `i` across the line before `line`, when it has one.
+fn mirror(i: usize, line: usize, len: usize) -> Option<usize> {
+    let size = line.min(len - line);
+    (line - size..line + size)
+        .contains(&i)
+        .then(|| 2 * line - 1 - i)
+}
+
+/// Pattern with a perfect horizontal line for part 1 and a vertical line off by a single cell
+/// for part 2. The horizontal line is off-centre so that the smudge can sit on a row it does not
+/// reflect.
+fn candidate(rng: &mut Rng, size: usize) -> Grid<bool> {
+    let (height, width) = (rng.range(5..=size), rng.range(5..=size));
+    let rows: Vec<usize> = (1..height).filter(|&a| 2 * a != height).collect();
+    let row_line = *rng.choose(&rows);
+    let col_line = rng.range(1..=width - 1);
+
+    let mut pattern = Grid::filled(height, width, false);
+    for r in 0..height {
+        for c in 0..width {
+            let r0 = mirror(r, row_line, height).map_or(r, |m| m.min(r));
+            let c0 = mirror(c, col_line, width).map_or(c, |m| m.min(c));
+            pattern[(r, c)] = match (r0, c0) == (r, c) {
+                true => rng.chance(50),
+                false => pattern[(r0, c0)],
+            };
+        }
+    }
+    let outside: Vec<usize> = (0..height)
+        .filter(|&r| mirror(r, row_line, height).is_none())
+        .collect();
+    let reflected = col_line.min(width - col_line);
+    let smudge = (
+        *rng.choose(&outside),
+        col_line - reflected + rng.below(2 * reflected),
+    );
+    pattern[smudge] = !pattern[smudge];
+    pattern
+}
+
+/// Draws candidates until no other line is perfect or off by one by chance.
+fn pattern(rng: &mut Rng, size: usize) -> Grid<bool> {
+    loop {
+        let pattern = candidate(rng, size);
+        let transposed = pattern.transpose();
+        let mut counts: Vec<usize> = (1..pattern.height())
+            .map(|line| mismatches(&pattern, line))
+            .chain((1..pattern.width()).map(|line| mismatches(&transposed, line)))
+            .filter(|&m| m <= 1)
+            .collect();
+        counts.sort();
+        if counts == [0, 1] {
+            return match rng.chance(50) {
+                true => transposed,
+                false => pattern,
+            };
+        }
+    }
+}
+
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    (0..sizes.get("patterns"))
+        .map(|_| {
+            pattern(rng, sizes.get("size"))
+                .map(|&rock| if rock { '#' } else { '.' })
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part2};
+    use aoc_core::Answer;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let input = GENERATOR.generate(seed, &[]).unwrap();
+            let patterns: Vec<&str> = input.split("\n\n").collect();
+            assert_eq!(patterns.len(), 100);
+            for pattern in patterns {
+                let (p1, p2) = (part1(pattern).unwrap(), part2(pattern).unwrap());
+                assert_ne!(p1, Answer::Unsigned(0), "{pattern}");
+                assert_ne!(p2, Answer::Unsigned(0), "{pattern}");
+                assert_ne!(p1, p2);
+            }
+        }
+    }
+}
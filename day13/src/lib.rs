@@ -0,0 +1,105 @@
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::{Answer, DayError, Part};
+use aoc_grid::{grid, Grid};
+use combine::{
+    choice,
+    parser::char::{char, newline},
+    sep_end_by1, ParseError, Parser, Stream,
+};
+
+pub mod generator;
+pub mod part1;
+pub mod part2;
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Island(Grid<bool>);
+
+pub fn is_palindrome(row: &[bool]) -> bool {
+    let mut i = 0;
+    while i < (row.len() / 2) {
+        if row[i] != row[row.len() - 1 - i] {
+            return false;
+        }
+        i += 1
+    }
+    true
+}
+
+fn symbol<Input>() -> impl Parser<Input, Output = bool>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    choice!(char('.').map(|_| false), char('#').map(|_| true))
+}
+
+fn islands<Input>() -> impl Parser<Input, Output = Vec<Island>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    sep_end_by1(grid(symbol()).map(Island), newline())
+}
+
+fn parse(input: &str) -> Result<Vec<Island>, DayError> {
+    aoc_parse::parse(islands(), input).map_err(DayError::from)
+}
+
+/// Columns left of each vertical mirror, plus 100 times the rows above each horizontal one,
+/// `find_mirror` telling where a part sees the mirror of an island.
+fn summarize(islands: Vec<Island>, find_mirror: fn(&Grid<bool>, bool) -> Option<usize>) -> usize {
+    let mut total = 0;
+    for island in islands.iter() {
+        if let Some(v) = find_mirror(&island.0, true) {
+            total += v;
+        } else if let Some(v) = find_mirror(&island.0, false) {
+            total += 100 * v;
+        }
+    }
+    total
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    parse(input).map(|islands| part1::solve(islands).into())
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    parse(input).map(|islands| part2::solve(islands).into())
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    let solve = if part == 1 {
+        part1::solve
+    } else {
+        part2::solve
+    };
+    bench::measure(input, runs, parse, |islands| Ok(solve(islands).into()))
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_palindrome() {
+        assert!(is_palindrome(&[true, false, false, true]));
+        assert!(!is_palindrome(&[true, false, true, true]));
+    }
+}
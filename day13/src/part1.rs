@@ -0,0 +1,98 @@
+use std::collections::HashSet;
+
+use aoc_grid::Grid;
+
+use crate::{is_palindrome, Island};
+
+pub fn find_mirror(grid: &Grid<bool>, row_direction: bool) -> Option<usize> {
+    let (n1, n2) = match row_direction {
+        true => (grid.height(), grid.width()),
+        false => (grid.width(), grid.height()),
+    };
+    //println!("{:?} {:?}", n1, n2);
+    let mut candidates: HashSet<usize> = (1..n2).collect();
+    for i in 0..n1 {
+        let pattern: Vec<bool> = match row_direction {
+            true => grid.row(i).to_vec(),
+            false => grid.column(i).copied().collect(),
+        };
+        let mut to_remove: Vec<usize> = vec![];
+        for &c in candidates.iter() {
+            let size = std::cmp::min(c, n2 - c);
+            let pattern = &pattern[c - size..c + size];
+            if !is_palindrome(pattern) {
+                to_remove.push(c);
+            }
+            //println!("{:?} {:?} {:?}", i, &pattern, to_remove)
+        }
+        to_remove.iter().for_each(|v| {
+            candidates.remove(v);
+        });
+    }
+    candidates.iter().next().copied()
+}
+
+pub(crate) fn solve(islands: Vec<Island>) -> usize {
+    crate::summarize(islands, find_mirror)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::islands;
+    use combine::EasyParser;
+
+    #[test]
+    fn test_parse_islands() {
+        let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+
+...##......##.#
+.#....#..##..#.
+#.####.##.#....
+##....##.#.#...
+#.####.#######.
+........#.#####
+##.#..##....#.#
+#.#..#.#.###...
+#.#..#.#.###...
+
+#.#.#.#.#
+###.#.#.#
+.##.#####
+.##.#####
+###.#.#.#
+#.#.#.#.#
+.#.##.#.#
+#.#..#..#
+..###.#..
+#..#.#.#.
+##.#...#.";
+        let (output, rest) = islands().easy_parse(input).unwrap();
+        //println!("{:?}", output);
+        assert!(rest.is_empty());
+        assert!(output[0].0[(0, 3)]);
+        assert!(!output[0].0[(0, 1)]);
+        assert!(output[1].0[(0, 0)]);
+
+        //test mirror
+        assert_eq!(find_mirror(&output[0].0, true), Some(5));
+        assert_eq!(find_mirror(&output[1].0, true), None);
+        assert_eq!(find_mirror(&output[1].0, false), Some(4));
+        assert_eq!(find_mirror(&output[2].0, false), Some(8));
+        assert_eq!(find_mirror(&output[2].0, true), None);
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use aoc_grid::Grid;
+
+use crate::{is_palindrome, Island};
+
+pub fn get_pattern(line: &[bool], mirror_idx: usize) -> Vec<bool> {
+    let size = std::cmp::min(mirror_idx, line.len() - mirror_idx);
+    line[mirror_idx - size..mirror_idx + size].to_vec()
+}
+
+pub fn find_mirror(grid: &Grid<bool>, vertical_mirror: bool) -> Option<usize> {
+    // search direction is the direction along which the mirror will be
+    // iter_n corresponds to the number of times we will search for mirrors
+    let (iter_n, search_n) = match vertical_mirror {
+        true => (grid.height(), grid.width()), // if the mirror is vertical, we iterate over rows
+        false => (grid.width(), grid.height()), //if the mirror is horizontal we iterate over columns
+    };
+    let mut candidates: HashMap<usize, (usize, Option<usize>)> =
+        (1..search_n).map(|v| (v, (1, None))).collect();
+
+    //get candidates : mirror positions where exactly 1 row/column is problematic
+    for i in 0..iter_n {
+        let line: Vec<bool> = match vertical_mirror {
+            true => grid.row(i).to_vec(),
+            false => grid.column(i).copied().collect(),
+        };
+        let mut to_remove: Vec<usize> = vec![];
+        for (&mirror_idx, v) in candidates.iter_mut() {
+            let pattern = get_pattern(&line, mirror_idx);
+            if !is_palindrome(&pattern) {
+                match v.0 {
+                    1 => {
+                        v.0 -= 1;
+                        v.1 = Some(i);
+                    }
+                    0 => {
+                        to_remove.push(mirror_idx);
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+        to_remove.iter().for_each(|v| {
+            candidates.remove(v);
+        });
+    }
+
+    // find fitting candidate
+    for (&mirror_idx, &(_, pos)) in candidates.iter() {
+        if let Some(i) = pos {
+            let line: Vec<bool> = match vertical_mirror {
+                true => grid.row(i).to_vec(),
+                false => grid.column(i).copied().collect(),
+            };
+            let mut pattern = get_pattern(&line, mirror_idx);
+            for k in 0..pattern.len() {
+                pattern[k] = !pattern[k];
+                if is_palindrome(&pattern) {
+                    return Some(mirror_idx);
+                }
+                pattern[k] = !pattern[k];
+            }
+        }
+    }
+    None
+}
+
+//candidates.iter().next().copied()
+
+pub(crate) fn solve(islands: Vec<Island>) -> usize {
+    crate::summarize(islands, find_mirror)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::islands;
+    use combine::EasyParser;
+
+    #[test]
+    fn test_parse_islands() {
+        let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+        let (output, rest) = islands().easy_parse(input).unwrap();
+        assert!(rest.is_empty());
+        assert!(output[0].0[(0, 3)]);
+        assert!(!output[0].0[(0, 1)]);
+        assert!(output[1].0[(0, 0)]);
+
+        //test mirror
+        assert_eq!(find_mirror(&output[0].0, true), None);
+        assert_eq!(find_mirror(&output[0].0, false), Some(3));
+        assert_eq!(find_mirror(&output[1].0, false), Some(1));
+    }
+
+    #[test]
+    fn test_is_palindrome() {
+        assert!(is_palindrome(&[true, false, false, true]));
+        assert!(!is_palindrome(&[true, false, true, true]));
+    }
+}
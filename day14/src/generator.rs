@@ -0,0 +1,35 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+use aoc_grid::Grid;
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "width",
+            default: 100,
+            range: 1..=10_000,
+            help: "number of columns",
+        },
+        Param {
+            name: "height",
+            default: 100,
+            range: 1..=10_000,
+            help: "number of rows",
+        },
+    ],
+    generate,
+};
+
+/// Round and cube-shaped rocks in about the proportions of the official input.
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let mut platform = Grid::filled(sizes.get("height"), sizes.get("width"), '.');
+    for row in 0..platform.height() {
+        for col in 0..platform.width() {
+            platform[(row, col)] = match rng.below(100) {
+                0..=19 => 'O',
+                20..=37 => '#',
+                _ => '.',
+            };
+        }
+    }
+    platform.to_string()
+}
@@ -0,0 +1,113 @@
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::{Answer, DayError, Part};
+use aoc_grid::{grid, Grid};
+use combine::{choice, parser::char::char, ParseError, Parser, Stream};
+
+pub mod generator;
+pub mod part1;
+pub mod part2;
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Copy)]
+pub enum Symbol {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl std::fmt::Display for Symbol {
+    // This trait requires `fmt` with this exact signature.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Symbol::Cube => write!(f, "#"),
+            Symbol::Round => write!(f, "O"),
+            Symbol::Empty => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Platform(Grid<Symbol>);
+
+fn symbol<Input>() -> impl Parser<Input, Output = Symbol>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    choice!(
+        char('.').map(|_| Symbol::Empty),
+        char('#').map(|_| Symbol::Cube),
+        char('O').map(|_| Symbol::Round)
+    )
+}
+
+fn platform<Input>() -> impl Parser<Input, Output = Platform>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    grid(symbol()).map(Platform)
+}
+
+fn parse(input: &str) -> Result<Platform, DayError> {
+    aoc_parse::parse(platform(), input).map_err(DayError::from)
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    parse(input).map(|platform| part1::solve(platform).into())
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    parse(input).map(|platform| part2::solve(platform).into())
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    let solve = if part == 1 {
+        part1::solve
+    } else {
+        part2::solve
+    };
+    bench::measure(input, runs, parse, |platform| Ok(solve(platform).into()))
+}
+
+pub fn bench_parse(_part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    bench::measure_parse(input, runs, parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    fn platform() -> impl Strategy<Value = String> {
+        (1usize..12, 1usize..12).prop_flat_map(|(width, height)| {
+            vec("[.#O]".prop_map(String::from), width * height).prop_map(move |cells| {
+                let rows: Vec<String> = cells.chunks(width).map(|row| row.concat()).collect();
+                rows.join("\n")
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn tilting_matches_weight(input in platform()) {
+            let mut platform = parse(&input).unwrap();
+            let weight = part1::solve(platform.clone());
+            platform.move_north();
+            prop_assert_eq!(platform.compute_support(), weight);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{Platform, Symbol};
+
+/// Load on the north beam of one column of `height` cells, read from north to south.
+pub fn get_weight<'a>(column: impl Iterator<Item = &'a Symbol>, height: usize) -> usize {
+    let mut total = 0;
+    let mut previous_cube: usize = height;
+    let mut cnt = 0;
+    for (i, s) in column.enumerate() {
+        match s {
+            Symbol::Cube => {
+                previous_cube = height - (i + 1);
+                cnt = 0;
+            }
+            Symbol::Round => {
+                total += previous_cube - cnt;
+                cnt += 1;
+            }
+            Symbol::Empty => {}
+        }
+    }
+    total
+}
+
+pub(crate) fn solve(platform: Platform) -> usize {
+    let height = platform.0.height();
+    platform
+        .0
+        .columns()
+        .map(|column| get_weight(column, height))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform;
+    use combine::EasyParser;
+
+    #[test]
+    fn test_parse_islands() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+        let (output, rest) = platform().easy_parse(input).unwrap();
+        //println!("{:?}", output);
+        assert!(rest.is_empty());
+        assert_eq!(get_weight(output.0.column(0), 10), 34);
+        assert_eq!(get_weight(output.0.column(2), 10), 17);
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use aoc_grid::Grid;
+
+use crate::{Platform, Symbol};
+
+impl Platform {
+    pub fn move_north(&mut self) {
+        let mut round_new_pos: HashSet<(usize, usize)> = HashSet::new();
+        //new pos
+        let height = self.0.height();
+        for (j, col) in self.0.columns().enumerate() {
+            let mut previous_cube: usize = height;
+            let mut cnt = 0;
+            for (i, s) in col.enumerate() {
+                match s {
+                    Symbol::Cube => {
+                        previous_cube = height - (i + 1);
+                        cnt = 0;
+                    }
+                    Symbol::Round => {
+                        let north_load = previous_cube - cnt;
+                        round_new_pos.insert((height - north_load, j));
+                        cnt += 1;
+                    }
+                    Symbol::Empty => {}
+                }
+            }
+        }
+        //update platform
+        for i in 0..self.0.height() {
+            for j in 0..self.0.width() {
+                match self.0[(i, j)] {
+                    Symbol::Cube => {}
+                    _ => {
+                        if round_new_pos.contains(&(i, j)) {
+                            self.0[(i, j)] = Symbol::Round;
+                        } else {
+                            self.0[(i, j)] = Symbol::Empty;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn cycle(&mut self, repeat: usize) {
+        let mut pos_history: Vec<Grid<Symbol>> = vec![];
+        for i in 0..repeat {
+            if let Some(j) = pos_history.iter().position(|p| p == &self.0) {
+                let cycle_length = i - j;
+                let remaining = (repeat - i) % cycle_length;
+                self.cycle(remaining);
+                return;
+            }
+            pos_history.push(self.0.clone());
+            for _ in 0..4 {
+                self.move_north();
+                self.0 = self.0.rotate_clockwise();
+            }
+        }
+    }
+
+    pub fn compute_support(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|(_, s)| **s == Symbol::Round)
+            .map(|((i, _), _)| self.0.height() - i)
+            .sum()
+    }
+}
+
+pub(crate) fn solve(mut platform: Platform) -> usize {
+    platform.cycle(1000000000);
+    platform.compute_support()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform;
+    use combine::EasyParser;
+
+    #[test]
+    fn test_parse_islands() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+        let (mut output, rest) = platform().easy_parse(input).unwrap();
+        assert!(rest.is_empty());
+        output.cycle(1000000000);
+        assert_eq!(output.compute_support(), 64);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let m = Grid::from_rows(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9],
+            vec![10, 11, 12],
+        ])
+        .unwrap();
+        let expected = Grid::from_rows(vec![
+            vec![10, 7, 4, 1],
+            vec![11, 8, 5, 2],
+            vec![12, 9, 6, 3],
+        ])
+        .unwrap();
+        assert_eq!(m.rotate_clockwise(), expected);
+    }
+}
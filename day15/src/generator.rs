@@ -0,0 +1,40 @@
+use aoc_core::generator::{Generator, Param, Rng, Sizes};
+
+pub const GENERATOR: Generator = Generator {
+    params: &[
+        Param {
+            name: "steps",
+            default: 4000,
+            range: 1..=100_000_000,
+            help: "number of steps",
+        },
+        Param {
+            name: "labels",
+            default: 500,
+            range: 1..=10_000_000,
+            help: "number of distinct lens labels",
+        },
+    ],
+    generate,
+};
+
+fn label(rng: &mut Rng) -> String {
+    (0..rng.range(2..=6))
+        .map(|_| char::from(b'a' + rng.below(26) as u8))
+        .collect()
+}
+
+/// Steps on a fixed set of labels, one out of three removing a lens.
+fn generate(rng: &mut Rng, sizes: &Sizes) -> String {
+    let labels: Vec<String> = (0..sizes.get("labels")).map(|_| label(rng)).collect();
+    (0..sizes.get("steps"))
+        .map(|_| {
+            let label = rng.choose(&labels);
+            match rng.chance(33) {
+                true => format!("{label}-"),
+                false => format!("{label}={}", rng.range(1..=9)),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,67 @@
+use aoc_core::bench::{self, Sample, Stats};
+use aoc_core::input::Source;
+use aoc_core::{Answer, DayError, Part};
+use combine::{parser::char::char, sep_by1, ParseError, Parser, Stream};
+
+pub mod generator;
+pub mod part1;
+pub mod part2;
+
+/// Puzzle input shipped with the crate.
+pub const INPUT: &str = include_str!("./input.txt");
+
+#[derive(Debug, Clone, Default, clap::Parser)]
+pub struct Opts {
+    /// Input file, `-` for stdin; the shipped `input.txt` when omitted
+    #[clap(short, long)]
+    pub input: Option<Source>,
+    #[clap(short, long, value_enum, default_value = "both")]
+    pub part: Part,
+}
+
+/// HASH of a step or of a label.
+fn hash(text: &str) -> usize {
+    let mut current: usize = 0;
+    for c in text.bytes() {
+        current += c as usize;
+        current *= 17;
+        current %= 256;
+    }
+    current
+}
+
+/// Initialization sequence, its `step`s being separated by commas.
+fn manual<Input, P>(step: P) -> impl Parser<Input, Output = Vec<P::Output>>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    P: Parser<Input>,
+{
+    sep_by1(step, char(','))
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    part1::compute(input).map(Answer::from)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    part2::compute(input).map(Answer::from)
+}
+
+pub fn bench(part: u8, input: &str, runs: usize) -> Result<Sample, DayError> {
+    match part {
+        1 => bench::measure(input, runs, part1::parse, |parsed| {
+            Ok(part1::solve(parsed).into())
+        }),
+        _ => bench::measure(input, runs, part2::parse, |parsed| {
+            Ok(part2::solve(parsed).into())
+        }),
+    }
+}
+
+pub fn bench_parse(part: u8, input: &str, runs: usize) -> Result<Stats, DayError> {
+    match part {
+        1 => bench::measure_parse(input, runs, part1::parse),
+        _ => bench::measure_parse(input, runs, part2::parse),
+    }
+}
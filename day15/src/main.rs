@@ -0,0 +1,10 @@
+use anyhow::Result;
+use aoc_core::input;
+use clap::Parser;
+use std::process::ExitCode;
+
+fn main() -> Result<ExitCode> {
+    let opts = day15::Opts::parse();
+    let input = input::read(opts.input.as_ref(), day15::INPUT)?;
+    Ok(aoc_core::run(opts.part, &input, day15::part1, day15::part2))
+}
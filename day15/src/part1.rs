@@ -0,0 +1,43 @@
+use aoc_core::DayError;
+use combine::{many1, parser::token::none_of, ParseError, Parser, Stream};
+
+use crate::{hash, manual};
+
+/// A step as written, hashed whole.
+fn step<Input>() -> impl Parser<Input, Output = String>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    many1(none_of(",\n".chars()))
+}
+
+pub(crate) fn parse(input: &str) -> Result<Vec<String>, DayError> {
+    aoc_parse::parse(manual(step()), input).map_err(DayError::from)
+}
+
+pub(crate) fn solve(steps: Vec<String>) -> usize {
+    steps.iter().map(|step| hash(step)).sum()
+}
+
+pub fn compute(input: &str) -> Result<usize, DayError> {
+    parse(input).map(solve)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use combine::EasyParser;
+
+    #[test]
+    fn test_parse_islands() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+        let (output, rest) = manual(step()).easy_parse(input).unwrap();
+        println!("{:?}", output);
+        assert!(rest.is_empty());
+        // assert_eq!(output.first().unwrap().0, "rn=1".as_bytes());
+        // assert_eq!(output.last().unwrap().0, "ot=7".as_bytes());
+        assert_eq!(solve(output), 1320);
+    }
+}
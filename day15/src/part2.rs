@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::string::String;
+
+use aoc_core::DayError;
+use combine::{
+    attempt, choice, many1,
+    parser::char::{char, digit, letter},
+    ParseError, Parser, Stream,
+};
+
+use crate::{hash, manual};
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Lenses(HashMap<usize, LensBox>);
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct LensBox {
+    pub queue: Vec<(String, usize)>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Actions {
+    Remove(String),
+    Add(String, u32),
+}
+
+fn step<Input>() -> impl Parser<Input, Output = Actions>
+where
+    Input: Stream<Token = char>,
+    Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+{
+    choice!(
+        attempt(many1(letter()).skip(char('-'))).map(|label: String| Actions::Remove(label)),
+        (many1(letter()).skip(char('=')), digit()).map(|(label, focal_length): (String, char)| {
+            Actions::Add(label, focal_length.to_digit(10).unwrap())
+        })
+    )
+}
+
+pub(crate) fn parse(input: &str) -> Result<Vec<Actions>, DayError> {
+    aoc_parse::parse(manual(step()), input).map_err(DayError::from)
+}
+
+/// Boxes once every step of the sequence has been carried out.
+fn arrange(steps: Vec<Actions>) -> Lenses {
+    let mut lenses: Lenses = Lenses::default();
+    for action in steps.into_iter() {
+        match action {
+            Actions::Remove(label) => {
+                let box_num = hash(&label);
+                if let Some(lens_box) = lenses.0.get_mut(&box_num) {
+                    lens_box
+                        .queue
+                        .retain(|(l, _): &(String, usize)| l != &label)
+                }
+            }
+            Actions::Add(label, focal_length) => {
+                let box_num = hash(&label);
+                match lenses.0.get_mut(&box_num) {
+                    Some(lens_box) => {
+                        match lens_box
+                            .queue
+                            .iter_mut()
+                            .find(|(l, _): &&mut (String, usize)| l == &label)
+                        {
+                            Some((_, ref mut f)) => {
+                                *f = focal_length as usize;
+                            }
+                            None => lens_box.queue.push((label, focal_length as usize)),
+                        }
+                    }
+                    None => {
+                        lenses.0.insert(
+                            box_num,
+                            LensBox {
+                                queue: vec![(label, focal_length as usize)],
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    }
+    lenses
+}
+
+pub(crate) fn solve(steps: Vec<Actions>) -> usize {
+    let mut total = 0;
+    arrange(steps)
+        .0
+        .into_iter()
+        .for_each(|(num_box, lens_box): (usize, LensBox)| {
+            lens_box.queue.iter().enumerate().for_each(
+                |(i, (_, focal_lenght)): (usize, &(String, usize))| {
+                    total += (num_box + 1) * (i + 1) * focal_lenght;
+                },
+            )
+        });
+    total
+}
+
+pub fn compute(input: &str) -> Result<usize, DayError> {
+    parse(input).map(solve)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrange() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+        let output = parse(input).map(arrange).unwrap();
+        assert_eq!(
+            output.0.get(&3).unwrap().queue,
+            vec![
+                (String::from("ot"), 7),
+                (String::from("ab"), 5),
+                (String::from("pc"), 6)
+            ]
+        );
+    }
+}